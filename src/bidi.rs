@@ -0,0 +1,142 @@
+//! Bidi-safe output for right-to-left texts
+//!
+//! Numbers mixed with `/`, `:` and spaces in persian text are reordered by the unicode bidi algorithm,
+//! so `1403/01/15 14:30` may show up scrambled in RTL UIs and PDFs.
+//! [BidiFormat] wraps any [Display] and surrounds each numeric run (digits with inner separators)
+//! with directional marks, so the runs keep their visual order
+//!
+//! # Example
+//!
+//! ```
+//! use persian_date::bidi::{BidiFormat, BidiMark};
+//!
+//! let text = BidiFormat::new("تاریخ: 1403/01/15 14:30", BidiMark::Isolate).to_string();
+//! assert_eq!(text, "تاریخ: \u{2066}1403/01/15\u{2069} \u{2066}14:30\u{2069}");
+//!
+//! let text = BidiFormat::new("مبلغ 12.5, قسط 3; تاریخ 1403/01/15", BidiMark::Rlm).with_persian_punctuation().to_string();
+//! assert_eq!(text, "مبلغ \u{200f}12٫5\u{200f}، قسط \u{200f}3\u{200f}؛ تاریخ \u{200f}1403/01/15\u{200f}");
+//! ```
+
+use core::fmt::{Display, Formatter, Write};
+
+/// Directional mark written around numeric runs
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum BidiMark {
+    /// Left-to-right isolate (U+2066) and pop directional isolate (U+2069), the most robust choice
+    #[default]
+    Isolate,
+    /// Left-to-right mark (U+200E) on both sides
+    Lrm,
+    /// Right-to-left mark (U+200F) on both sides
+    Rlm,
+    /// Arabic letter mark (U+061C) on both sides
+    Alm,
+}
+
+impl BidiMark {
+    fn open(&self) -> char {
+        match self {
+            BidiMark::Isolate => '\u{2066}',
+            BidiMark::Lrm => '\u{200e}',
+            BidiMark::Rlm => '\u{200f}',
+            BidiMark::Alm => '\u{061c}',
+        }
+    }
+
+    fn close(&self) -> char {
+        match self {
+            BidiMark::Isolate => '\u{2069}',
+            mark => mark.open(),
+        }
+    }
+}
+
+/// Text written with directional marks around numeric runs, see [module](self)
+#[derive(Clone)]
+pub struct BidiFormat<T> {
+    inner : T,
+    mark : BidiMark,
+    persian_punctuation : bool,
+}
+
+impl<T : Display> BidiFormat<T> {
+    pub fn new(inner : T, mark : BidiMark) -> Self {
+        BidiFormat { inner, mark, persian_punctuation: false }
+    }
+
+    /// Use persian punctuation : `،` for comma, `؛` for semicolon and `٫` for decimal point in numbers
+    pub fn with_persian_punctuation(mut self) -> Self {
+        self.persian_punctuation = true;
+        self
+    }
+}
+
+impl<T : Display> Display for BidiFormat<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let mut writer = BidiWriter { inner: f, mark: self.mark, persian_punctuation: self.persian_punctuation, in_run: false, pending: None };
+        write!(writer, "{}", self.inner)?;
+        writer.finish()
+    }
+}
+
+/// Writer which tracks numeric runs, a separator is kept pending until it is known to be inside a run
+struct BidiWriter<'a, W : Write + ?Sized> {
+    inner : &'a mut W,
+    mark : BidiMark,
+    persian_punctuation : bool,
+    in_run : bool,
+    pending : Option<char>,
+}
+
+impl<W : Write + ?Sized> BidiWriter<'_, W> {
+    fn write_separator(&mut self, c : char, in_number : bool) -> core::fmt::Result {
+        let c = match c {
+            '.' if self.persian_punctuation && in_number => '٫',
+            ',' if self.persian_punctuation && in_number => '٬',
+            ',' if self.persian_punctuation => '،',
+            ';' if self.persian_punctuation => '؛',
+            c => c,
+        };
+        self.inner.write_char(c)
+    }
+
+    fn close_run(&mut self) -> core::fmt::Result {
+        if self.in_run {
+            self.inner.write_char(self.mark.close())?;
+            self.in_run = false;
+        }
+        match self.pending.take() {
+            Some(c) => self.write_separator(c, false),
+            None => Ok(()),
+        }
+    }
+
+    fn finish(&mut self) -> core::fmt::Result {
+        self.close_run()
+    }
+}
+
+impl<W : Write + ?Sized> Write for BidiWriter<'_, W> {
+    fn write_str(&mut self, s : &str) -> core::fmt::Result {
+        s.chars().try_for_each(|c| self.write_char(c))
+    }
+
+    fn write_char(&mut self, c : char) -> core::fmt::Result {
+        if c.is_numeric() {
+            if !self.in_run {
+                self.inner.write_char(self.mark.open())?;
+                self.in_run = true;
+            }
+            if let Some(separator) = self.pending.take() {
+                self.write_separator(separator, true)?;
+            }
+            return self.inner.write_char(c);
+        }
+        if self.in_run && self.pending.is_none() && matches!(c, '/' | ':' | '.' | ',' | '-' | '٫' | '٬') {
+            self.pending = Some(c);
+            return Ok(());
+        }
+        self.close_run()?;
+        self.write_separator(c, false)
+    }
+}
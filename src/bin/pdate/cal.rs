@@ -0,0 +1,89 @@
+//! `pdate cal` prints jalali calendars, like util-linux `cal`
+
+use std::io::IsTerminal;
+use persian_date::locale::Digits;
+use persian_date::render::{GregorianDays, TextCalendar, TextCalendarOptions, TextDirection};
+use persian_date::structure::{PDate, Setter};
+
+const USAGE : &str = "Usage: pdate cal [OPTION]... [[month] year]
+Display a jalali calendar, the current month by default
+
+  -y, --year             display the whole year (current year without arguments)
+  -s, --sunday           weeks start on sunday (default saturday)
+  -m, --monday           weeks start on monday
+  -g, --gregorian        display the gregorian day next to each day
+      --digits=DIGITS    digits of numbers : persian (default), latin or arabic
+      --rtl              start lines with right-to-left marks, for bidi aware viewers
+      --color[=WHEN]     highlight today, weekends and holidays : auto (default), always or never
+  -z, --zone=TZ          time zone of today (default $TZ, then Asia/Tehran)
+  -h, --help             display this help
+
+A single number above 12 is a year, otherwise the month of current year";
+
+pub fn run(args : &[String]) -> Result<(), String> {
+    let mut options = TextCalendarOptions::default();
+    let mut whole_year = false;
+    let mut color = None;
+    let mut zone = None;
+    let mut numbers = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let (name, inline) = match arg.split_once('=') {
+            Some((name, value)) if name.starts_with("--") => (name, Some(value)),
+            _ => (arg.as_str(), None),
+        };
+        match name {
+            "-h" | "--help" => {
+                return crate::print(format_args!("{}\n", USAGE));
+            }
+            "-y" | "--year" => whole_year = true,
+            "-s" | "--sunday" => options.week_start = 1,
+            "-m" | "--monday" => options.week_start = 2,
+            "-g" | "--gregorian" => options.gregorian = GregorianDays::Beside,
+            "--rtl" => options.direction = TextDirection::Rtl,
+            "--digits" => {
+                let value = inline.map(str::to_string).or_else(|| args.next().cloned()).ok_or("option '--digits' requires an argument")?;
+                options.digits = Some(match value.as_str() {
+                    "persian" => Digits::Persian,
+                    "latin" => Digits::Latin,
+                    "arabic" => Digits::Arabic,
+                    _ => return Err(format!("invalid digits '{}'", value)),
+                });
+            }
+            "--color" => color = Some(match inline.unwrap_or("auto") {
+                "auto" => std::io::stdout().is_terminal(),
+                "always" => true,
+                "never" => false,
+                value => return Err(format!("invalid color mode '{}'", value)),
+            }),
+            "-z" | "--zone" => zone = Some(inline.map(str::to_string).or_else(|| args.next().cloned()).ok_or(format!("option '{}' requires an argument", name))?),
+            _ => match crate::normalize_digits(arg).parse::<i32>() {
+                Ok(number) if numbers.len() < 2 => numbers.push(number),
+                _ => return Err(format!("invalid argument '{}'\nTry 'pdate cal --help' for more information.", arg)),
+            },
+        }
+    }
+    let mut today = PDate::now();
+    today.set_time_zone(crate::time_zone(zone.as_deref())?);
+    let today = today.jalali_date();
+    if color.unwrap_or_else(|| std::io::stdout().is_terminal()) {
+        options.today = Some(today);
+        options.holidays = true;
+    }
+    let (month, year) = match numbers[..] {
+        [] => (today.month(), today.year()),
+        [year] if year > 12 => {
+            whole_year = true;
+            (1, year)
+        }
+        [month] => (u32::try_from(month).unwrap_or(0), today.year()),
+        [month, year] => (u32::try_from(month).unwrap_or(0), year),
+        _ => unreachable!(),
+    };
+    let calendar = if whole_year {
+        TextCalendar::year(year, options)
+    } else {
+        TextCalendar::month(year, month, options)
+    };
+    crate::print(calendar.ok_or(format!("invalid month {} of year {}", month, year))?)
+}
@@ -0,0 +1,167 @@
+//! `pdate csv` converts date columns of CSV files between jalali and gregorian
+
+use std::fs::File;
+use std::io::{self, Read};
+use chrono::{Datelike, NaiveDate};
+use chrono_tz::Tz;
+use persian_date::calendar;
+use persian_date::format::FormatItems;
+use persian_date::structure::{NaiveJalaliDate, PDate};
+
+const USAGE : &str = "Usage: pdate csv [OPTION]... [FILE]
+Convert date columns of CSV from jalali to gregorian (or back with --to-jalali), reading FILE or stdin
+
+  -c, --columns=LIST      columns to convert, header names or 1-based indexes, comma separated
+  -i, --input-format=F    format of input dates (default %Y/%m/%d for jalali, %Y-%m-%d for gregorian)
+  -o, --output-format=F   format of output dates (default %Y-%m-%d for gregorian, %Y/%m/%d for jalali)
+      --to-jalali         convert gregorian dates to jalali
+  -d, --delimiter=CHAR    field delimiter (default ,)
+      --no-header         first row is data, columns are selected by index
+      --strict            stop on the first invalid date
+  -h, --help              display this help
+
+Jalali formats use persian_date::format directives, gregorian formats use chrono directives,
+like %Y%m%d for integer dates (14030115). Invalid and out of range dates are reported on stderr and kept as is";
+
+/// Conversion of a date column
+struct Conversion {
+    to_jalali : bool,
+    input_format : String,
+    output_format : String,
+}
+
+impl Conversion {
+    fn convert(&self, value : &str, jalali_items : &FormatItems) -> Option<String> {
+        if self.to_jalali {
+            let date = NaiveDate::parse_from_str(value, &self.input_format).ok()?;
+            let (year, month, day) = calendar::checked_gregorian_to_jalali(date.year(), date.month(), date.day())?;
+            let date = PDate::from_naive_jalali(NaiveJalaliDate::from_ymd_opt(year, month, day)?, 0, 0, 0, Tz::UTC);
+            let text = date.format_with_items(jalali_items).to_string();
+            Some(text)
+        } else {
+            let date = NaiveJalaliDate::parse_from_str(value, &self.input_format).ok()?;
+            Some(date.to_gregorian().format(&self.output_format).to_string())
+        }
+    }
+}
+
+pub fn run(args : &[String]) -> Result<(), String> {
+    let mut columns = None;
+    let mut input_format = None;
+    let mut output_format = None;
+    let mut to_jalali = false;
+    let mut delimiter = b',';
+    let mut has_header = true;
+    let mut strict = false;
+    let mut path = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let (name, inline) = match arg.split_once('=') {
+            Some((name, value)) if name.starts_with("--") => (name, Some(value.to_string())),
+            _ => (arg.as_str(), None),
+        };
+        let mut value = |name : &str| inline.clone().or_else(|| args.next().cloned()).ok_or(format!("option '{}' requires an argument", name));
+        match name {
+            "-h" | "--help" => return crate::print(format_args!("{}\n", USAGE)),
+            "-c" | "--columns" => columns = Some(value(name)?),
+            "-i" | "--input-format" => input_format = Some(value(name)?),
+            "-o" | "--output-format" => output_format = Some(value(name)?),
+            "--to-jalali" => to_jalali = true,
+            "-d" | "--delimiter" => {
+                let value = value(name)?;
+                delimiter = match value.as_bytes() {
+                    [byte] => *byte,
+                    _ if value == "\\t" => b'\t',
+                    _ => return Err(format!("invalid delimiter '{}'", value)),
+                };
+            }
+            "--no-header" => has_header = false,
+            "--strict" => strict = true,
+            _ if path.is_none() && (!arg.starts_with('-') || arg == "-") => path = Some(arg.clone()),
+            _ => return Err(format!("invalid argument '{}'\nTry 'pdate csv --help' for more information.", arg)),
+        }
+    }
+    let columns = columns.ok_or("option '--columns' is required\nTry 'pdate csv --help' for more information.")?;
+    let (jalali_format, gregorian_format) = ("%Y/%m/%d".to_string(), "%Y-%m-%d".to_string());
+    let conversion = match to_jalali {
+        true => Conversion { to_jalali, input_format: input_format.unwrap_or(gregorian_format), output_format: output_format.unwrap_or(jalali_format) },
+        false => Conversion { to_jalali, input_format: input_format.unwrap_or(jalali_format), output_format: output_format.unwrap_or(gregorian_format) },
+    };
+    let input : Box<dyn Read> = match path.as_deref() {
+        None | Some("-") => Box::new(io::stdin().lock()),
+        Some(path) => Box::new(File::open(path).map_err(|error| format!("{}: {}", path, error))?),
+    };
+    let mut reader = csv::ReaderBuilder::new().delimiter(delimiter).has_headers(false).flexible(true).from_reader(input);
+    let mut writer = csv::WriterBuilder::new().delimiter(delimiter).flexible(true).from_writer(io::stdout().lock());
+    let jalali_items = FormatItems::parse(&conversion.output_format);
+    let mut record = csv::StringRecord::new();
+    let mut indexes = None;
+    let mut invalid = 0;
+    let mut converted = Vec::new();
+    while reader.read_record(&mut record).map_err(|error| error.to_string())? {
+        let line = record.position().map_or(0, |position| position.line());
+        let indexes = match &indexes {
+            Some(indexes) => indexes,
+            None => {
+                let header = if has_header { Some(&record) } else { None };
+                let found = column_indexes(&columns, header)?;
+                if has_header {
+                    indexes = Some(found);
+                    if !write_record(&mut writer, record.iter())? {
+                        return Ok(());
+                    }
+                    continue;
+                }
+                indexes.insert(found)
+            }
+        };
+        converted.clear();
+        for (index, value) in record.iter().enumerate() {
+            let trimmed = value.trim();
+            if !indexes.contains(&index) || trimmed.is_empty() {
+                converted.push(value.to_string());
+                continue;
+            }
+            match conversion.convert(trimmed, &jalali_items) {
+                Some(date) => converted.push(date),
+                None => {
+                    invalid += 1;
+                    eprintln!("pdate: line {}, column {}: invalid date '{}'", line, index + 1, value);
+                    if strict {
+                        writer.flush().map_err(|error| error.to_string())?;
+                        return Err("stopped on invalid date".to_string());
+                    }
+                    converted.push(value.to_string());
+                }
+            }
+        }
+        if !write_record(&mut writer, converted.iter())? {
+            return Ok(());
+        }
+    }
+    writer.flush().or_else(|error| if error.kind() == io::ErrorKind::BrokenPipe { Ok(()) } else { Err(error.to_string()) })?;
+    match invalid {
+        0 => Ok(()),
+        count => Err(format!("{} invalid dates", count)),
+    }
+}
+
+/// Return `false` when output is closed (like `pdate csv | head`)
+fn write_record<W : io::Write>(writer : &mut csv::Writer<W>, record : impl IntoIterator<Item = impl AsRef<[u8]>>) -> Result<bool, String> {
+    match writer.write_record(record) {
+        Ok(()) => Ok(true),
+        Err(error) if matches!(error.kind(), csv::ErrorKind::Io(error) if error.kind() == io::ErrorKind::BrokenPipe) => Ok(false),
+        Err(error) => Err(error.to_string()),
+    }
+}
+
+/// Return 0-based indexes of columns, by 1-based index or by name in header
+fn column_indexes(columns : &str, header : Option<&csv::StringRecord>) -> Result<Vec<usize>, String> {
+    columns.split(',').map(str::trim).map(|column| {
+        if let Ok(index) = column.parse::<usize>() {
+            return index.checked_sub(1).ok_or(format!("invalid column index '{}'", column));
+        }
+        header.and_then(|header| header.iter().position(|name| name.trim() == column))
+            .ok_or(format!("unknown column '{}'", column))
+    }).collect()
+}
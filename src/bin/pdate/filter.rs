@@ -0,0 +1,199 @@
+//! `pdate filter` rewrites timestamps of a text stream to jalali dates
+
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use chrono::{DateTime, Datelike, NaiveDateTime, TimeZone};
+use chrono_tz::Tz;
+use regex::{Captures, Regex};
+use persian_date::format::FormatItems;
+
+const USAGE : &str = "Usage: pdate filter [OPTION]...
+Copy stdin to stdout, rewriting timestamps to jalali dates
+
+  -f, --from=FORMATS     comma separated known formats of timestamps (default rfc3339) :
+                           rfc3339   2024-04-03T09:30:00Z, 2024-04-03 09:30:00.123+03:30
+                           iso       2024-04-03 09:30:00, local time in --zone
+                           epoch     1712136600 (10 digits, seconds)
+                           epoch-ms  1712136600000 (13 digits, milliseconds)
+                           clf       03/Apr/2024:09:30:00 +0330 (apache and nginx logs)
+                           syslog    Apr  3 09:30:00, in current year
+  -r, --regex=REGEX      find timestamps with REGEX instead, the timestamp is group `ts`,
+                         the first group or the whole match
+  -i, --input-format=F   chrono format of timestamps found by --regex (default: try --from formats)
+  -t, --to=FORMAT        output format (default %Y/%m/%d %H:%M:%S)
+  -z, --zone=TZ          time zone of output and local timestamps (default $TZ, then Asia/Tehran)
+  -h, --help             display this help
+
+Timestamps which can not be parsed or are out of range are left unchanged";
+
+/// Known timestamp format
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Known {
+    Rfc3339,
+    Iso,
+    Epoch,
+    EpochMillis,
+    Clf,
+    Syslog,
+}
+
+impl Known {
+    const ALL : [Known; 6] = [Known::Rfc3339, Known::Iso, Known::Epoch, Known::EpochMillis, Known::Clf, Known::Syslog];
+
+    fn from_name(name : &str) -> Option<Known> {
+        Known::ALL.into_iter().find(|known| known.name() == name)
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Known::Rfc3339 => "rfc3339",
+            Known::Iso => "iso",
+            Known::Epoch => "epoch",
+            Known::EpochMillis => "epoch-ms",
+            Known::Clf => "clf",
+            Known::Syslog => "syslog",
+        }
+    }
+
+    /// Name of regex group of format
+    fn group(&self) -> &'static str {
+        match self {
+            Known::EpochMillis => "epoch_ms",
+            known => known.name(),
+        }
+    }
+
+    fn pattern(&self) -> &'static str {
+        match self {
+            Known::Rfc3339 => r"\b\d{4}-\d{2}-\d{2}[T ]\d{2}:\d{2}:\d{2}(?:\.\d+)?(?:Z|[+-]\d{2}(?::?\d{2})?)",
+            Known::Iso => r"\b\d{4}-\d{2}-\d{2}[T ]\d{2}:\d{2}:\d{2}(?:\.\d+)?\b",
+            Known::Epoch => r"\b\d{10}(?:\.\d{1,9})?\b",
+            Known::EpochMillis => r"\b\d{13}\b",
+            Known::Clf => r"\b\d{2}/[A-Z][a-z]{2}/\d{4}:\d{2}:\d{2}:\d{2} [+-]\d{4}",
+            Known::Syslog => r"\b[A-Z][a-z]{2} [ \d]\d \d{2}:\d{2}:\d{2}\b",
+        }
+    }
+
+    /// Return milliseconds since epoch of timestamp
+    fn parse(&self, text : &str, zone : Tz) -> Option<i64> {
+        match self {
+            Known::Rfc3339 => {
+                let text = text.replacen(' ', "T", 1).replace('Z', "+00:00");
+                DateTime::parse_from_str(&text, "%Y-%m-%dT%H:%M:%S%.f%#z").ok().map(|date| date.timestamp_millis())
+            }
+            Known::Iso => local_millis(NaiveDateTime::parse_from_str(&text.replacen('T', " ", 1), "%Y-%m-%d %H:%M:%S%.f").ok()?, zone),
+            Known::Epoch => text.parse::<f64>().ok().filter(|seconds| seconds.is_finite()).map(|seconds| (seconds * 1000.0).round() as i64),
+            Known::EpochMillis => text.parse().ok(),
+            Known::Clf => DateTime::parse_from_str(text, "%d/%b/%Y:%H:%M:%S %z").ok().map(|date| date.timestamp_millis()),
+            Known::Syslog => {
+                let year = chrono::Utc::now().with_timezone(&zone).year();
+                local_millis(NaiveDateTime::parse_from_str(&format!("{} {}", year, text), "%Y %b %e %H:%M:%S").ok()?, zone)
+            }
+        }
+    }
+}
+
+fn local_millis(time : NaiveDateTime, zone : Tz) -> Option<i64> {
+    zone.from_local_datetime(&time).earliest().map(|date| date.timestamp_millis())
+}
+
+/// How timestamps are found and parsed
+struct Matcher {
+    regex : Regex,
+    /// Formats tried on timestamps of custom regex, or the formats of groups of known regex
+    formats : Vec<Known>,
+    custom : bool,
+    input_format : Option<String>,
+    zone : Tz,
+}
+
+impl Matcher {
+    /// Return the span of timestamp in match and its milliseconds since epoch
+    fn timestamp(&self, captures : &Captures) -> Option<(usize, usize, Option<i64>)> {
+        if !self.custom {
+            let known = self.formats.iter().find(|known| captures.name(known.group()).is_some())?;
+            let found = captures.name(known.group())?;
+            return Some((found.start(), found.end(), known.parse(found.as_str(), self.zone)));
+        }
+        let found = captures.name("ts").or_else(|| captures.get(1)).or_else(|| captures.get(0))?;
+        let text = found.as_str();
+        let millis = match &self.input_format {
+            Some(format) => DateTime::parse_from_str(text, format).map(|date| date.timestamp_millis()).ok()
+                .or_else(|| local_millis(NaiveDateTime::parse_from_str(text, format).ok()?, self.zone)),
+            None => self.formats.iter().find_map(|known| known.parse(text, self.zone)),
+        };
+        Some((found.start(), found.end(), millis))
+    }
+}
+
+pub fn run(args : &[String]) -> Result<(), String> {
+    let mut formats = vec![Known::Rfc3339];
+    let mut regex = None;
+    let mut input_format = None;
+    let mut output = "%Y/%m/%d %H:%M:%S".to_string();
+    let mut zone = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let (name, inline) = match arg.split_once('=') {
+            Some((name, value)) if name.starts_with("--") => (name, Some(value.to_string())),
+            _ => (arg.as_str(), None),
+        };
+        let mut value = |name : &str| inline.clone().or_else(|| args.next().cloned()).ok_or(format!("option '{}' requires an argument", name));
+        match name {
+            "-h" | "--help" => return crate::print(format_args!("{}\n", USAGE)),
+            "-f" | "--from" => {
+                formats = value(name)?.split(',').map(|format| Known::from_name(format.trim()).ok_or(format!("unknown format '{}'", format)))
+                    .collect::<Result<_, _>>()?;
+            }
+            "-r" | "--regex" => regex = Some(value(name)?),
+            "-i" | "--input-format" => input_format = Some(value(name)?),
+            "-t" | "--to" => output = value(name)?,
+            "-z" | "--zone" => zone = Some(value(name)?),
+            _ => return Err(format!("invalid argument '{}'\nTry 'pdate filter --help' for more information.", arg)),
+        }
+    }
+    let zone = crate::time_zone(zone.as_deref())?;
+    let matcher = match regex {
+        Some(regex) => Matcher { regex: Regex::new(&regex).map_err(|error| error.to_string())?, formats, custom: true, input_format, zone },
+        None => {
+            // rfc3339 is tried before iso, which matches its prefix
+            let formats : Vec<Known> = Known::ALL.into_iter().filter(|known| formats.contains(known)).collect();
+            let pattern = formats.iter().map(|known| format!("(?P<{}>{})", known.group(), known.pattern())).collect::<Vec<_>>().join("|");
+            Matcher { regex: Regex::new(&pattern).map_err(|error| error.to_string())?, formats, custom: false, input_format, zone }
+        }
+    };
+    let items = FormatItems::parse(&output);
+    filter(&matcher, &items, &mut BufReader::new(io::stdin().lock()), &mut BufWriter::new(io::stdout().lock()))
+        .or_else(|error| if error.kind() == io::ErrorKind::BrokenPipe { Ok(()) } else { Err(error.to_string()) })
+}
+
+fn filter(matcher : &Matcher, items : &FormatItems, input : &mut BufReader<impl Read>, output : &mut impl Write) -> io::Result<()> {
+    let mut line = Vec::new();
+    loop {
+        line.clear();
+        if input.read_until(b'\n', &mut line)? == 0 {
+            return output.flush();
+        }
+        // lines which are not utf-8 are copied as is
+        let Ok(text) = std::str::from_utf8(&line) else {
+            output.write_all(&line)?;
+            continue;
+        };
+        let mut last = 0;
+        for captures in matcher.regex.captures_iter(text) {
+            let Some((start, end, Some(millis))) = matcher.timestamp(&captures) else {
+                continue;
+            };
+            let Some(date) = crate::in_zone(millis, matcher.zone) else {
+                continue;
+            };
+            output.write_all(&line[last..start])?;
+            write!(output, "{}", date.format_with_items(items))?;
+            last = end;
+        }
+        output.write_all(&line[last..])?;
+        // flush when no more input is waiting, so `tail -f` streams are not delayed
+        if input.buffer().is_empty() {
+            output.flush()?;
+        }
+    }
+}
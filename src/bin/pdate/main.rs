@@ -0,0 +1,259 @@
+//! `pdate` prints and converts jalali dates, like GNU `date`
+
+mod cal;
+mod csv;
+mod filter;
+
+use std::env;
+use std::fmt::Display;
+use std::io::{self, Write};
+use std::process::ExitCode;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone};
+use chrono_tz::Tz;
+use persian_date::duration::{DurationOptions, FormatDuration};
+use persian_date::parse::ParseError;
+use persian_date::structure::{NaiveJalaliDate, PDate, Reader, Setter};
+
+const USAGE : &str = "Usage: pdate [OPTION]... [+FORMAT]
+  or:  pdate cal [OPTION]... [[month] year]
+  or:  pdate filter [OPTION]...
+  or:  pdate csv [OPTION]... [FILE]
+Display the current jalali date and time, or the date described by --date
+
+  -d, --date=STRING      display date of STRING: jalali (1403/01/15 09:30), gregorian (2024-04-03),
+                         RFC 3339, @EPOCH seconds or a persian phrase (فردا ساعت ۱۰)
+  -z, --zone=TZ          time zone, like Asia/Tehran (default $TZ, then Asia/Tehran)
+  -g, --to-gregorian     display the gregorian date, FORMAT uses chrono directives
+      --add=DURATION     add a duration to the date, like 3d, -2w, 1y6mo, 1h30m
+                         (units y, mo, w, d, h, m, s)
+      --diff A B         display the calendar difference from date A to date B
+  -h, --help             display this help
+
+FORMAT uses the directives of persian_date::format, like +%Y/%m/%d %H:%M:%S
+See 'pdate cal --help' for calendars, 'pdate filter --help' for rewriting timestamps of logs
+and 'pdate csv --help' for converting date columns of CSV files";
+
+const DEFAULT_FORMAT : &str = "%A %d %B %Y %H:%M:%S %:z";
+const DEFAULT_GREGORIAN_FORMAT : &str = "%Y-%m-%d %H:%M:%S %:z";
+const OUT_OF_RANGE : &str = "date is out of range";
+
+/// Parsed command line
+#[derive(Default)]
+struct Options {
+    date : Option<String>,
+    zone : Option<String>,
+    to_gregorian : bool,
+    add : Option<String>,
+    diff : Option<(String, String)>,
+    format : Option<String>,
+}
+
+fn main() -> ExitCode {
+    let args : Vec<String> = env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("cal") => cal::run(&args[1..]),
+        Some("filter") => filter::run(&args[1..]),
+        Some("csv") => csv::run(&args[1..]),
+        _ => run(&args),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("pdate: {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args : &[String]) -> Result<(), String> {
+    let Some(options) = parse_args(args)? else {
+        return print(format_args!("{}\n", USAGE));
+    };
+    let zone = time_zone(options.zone.as_deref())?;
+    if let Some((from, to)) = &options.diff {
+        let (from, to) = (parse_date(from, zone)?, parse_date(to, zone)?);
+        let options = DurationOptions { precision: 6, ..Default::default() };
+        return print(format_args!("{}\n", to.calendar_diff(&from).format_duration(&options)));
+    }
+    let mut date = match &options.date {
+        Some(input) => parse_date(input, zone)?,
+        None => in_zone(PDate::now().time_millis(), zone).ok_or(OUT_OF_RANGE)?,
+    };
+    if let Some(duration) = &options.add {
+        date = add_duration(&date, duration)?;
+    }
+    if options.to_gregorian {
+        let date = zone.timestamp_millis_opt(date.time_millis()).single().ok_or(OUT_OF_RANGE)?;
+        print(format_args!("{}\n", date.format(options.format.as_deref().unwrap_or(DEFAULT_GREGORIAN_FORMAT))))
+    } else {
+        print(format_args!("{}\n", date.format_lazy(options.format.as_deref().unwrap_or(DEFAULT_FORMAT))))
+    }
+}
+
+/// Write to stdout, a closed pipe (like `pdate cal -y | head`) is not an error
+fn print(text : impl Display) -> Result<(), String> {
+    match write!(io::stdout().lock(), "{}", text) {
+        Err(error) if error.kind() != io::ErrorKind::BrokenPipe => Err(error.to_string()),
+        _ => Ok(()),
+    }
+}
+
+/// Return `None` when help is requested
+fn parse_args(args : &[String]) -> Result<Option<Options>, String> {
+    let mut options = Options::default();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let (name, inline) = match arg.split_once('=') {
+            Some((name, value)) if name.starts_with("--") => (name, Some(value.to_string())),
+            _ => (arg.as_str(), None),
+        };
+        let mut value = |name : &str| inline.clone().or_else(|| args.next().cloned()).ok_or(format!("option '{}' requires an argument", name));
+        match name {
+            "-h" | "--help" => return Ok(None),
+            "-d" | "--date" => options.date = Some(value(name)?),
+            "-z" | "--zone" => options.zone = Some(value(name)?),
+            "-g" | "--to-gregorian" => options.to_gregorian = true,
+            "--add" => options.add = Some(value(name)?),
+            "--diff" => {
+                let from = value(name)?;
+                let to = args.next().cloned().ok_or("option '--diff' requires two dates")?;
+                options.diff = Some((from, to));
+            }
+            _ if arg.starts_with('+') && options.format.is_none() => options.format = Some(arg[1..].to_string()),
+            _ => return Err(format!("invalid argument '{}'\nTry 'pdate --help' for more information.", arg)),
+        }
+    }
+    Ok(Some(options))
+}
+
+fn time_zone(name : Option<&str>) -> Result<Tz, String> {
+    match name.map(str::to_string).or_else(|| env::var("TZ").ok().filter(|tz| !tz.is_empty())) {
+        Some(name) => name.parse().map_err(|_| format!("invalid time zone '{}'", name)),
+        None => Ok(Tz::Asia__Tehran),
+    }
+}
+
+/// Return `None` when the date is out of range
+fn in_zone(millis : i64, zone : Tz) -> Option<PDate> {
+    let mut date = PDate::default();
+    date.set_time_zone(zone);
+    date.set_time_millis(millis).ok()?;
+    Some(date)
+}
+
+/// Convert persian and arabic digits to latin
+fn normalize_digits(input : &str) -> String {
+    input.chars().map(|c| match c {
+        '۰'..='۹' => char::from(b'0' + (c as u32 - '۰' as u32) as u8),
+        '٠'..='٩' => char::from(b'0' + (c as u32 - '٠' as u32) as u8),
+        c => c,
+    }).collect()
+}
+
+/// Parse a date in one of the supported forms, see [USAGE]
+fn parse_date(input : &str, zone : Tz) -> Result<PDate, String> {
+    let normalized = normalize_digits(input.trim());
+    if let Some(seconds) = normalized.strip_prefix('@') {
+        let seconds : i64 = seconds.parse().map_err(|_| format!("invalid date '{}'", input))?;
+        return seconds.checked_mul(1000).and_then(|millis| in_zone(millis, zone)).ok_or(OUT_OF_RANGE.to_string());
+    }
+    if let Ok(date) = DateTime::parse_from_rfc3339(&normalized) {
+        return in_zone(date.timestamp_millis(), zone).ok_or(OUT_OF_RANGE.to_string());
+    }
+    if let Some(date) = parse_numeric(&normalized, zone) {
+        return Ok(date);
+    }
+    let now = in_zone(PDate::now().time_millis(), zone).ok_or(OUT_OF_RANGE)?;
+    match PDate::parse_natural(input, &now) {
+        Ok(date) => in_zone(date.start().time_millis(), zone).ok_or(OUT_OF_RANGE.to_string()),
+        Err(ParseError::OutOfRange) => Err(OUT_OF_RANGE.to_string()),
+        Err(_) => Err(format!("invalid date '{}'", input)),
+    }
+}
+
+/// Parse `year/month/day [hour:minute[:second]]`, with `/` or `-` separators
+///
+/// Years from 1700 are gregorian, earlier years are jalali
+fn parse_numeric(input : &str, zone : Tz) -> Option<PDate> {
+    let (date, time) = match input.split_once([' ', 'T']) {
+        Some((date, time)) => (date, Some(time.trim())),
+        None => (input, None),
+    };
+    let mut fields = date.split(['/', '-']).map(|field| field.parse::<u32>().ok());
+    let (year, month, day) = (fields.next()??, fields.next()??, fields.next()??);
+    if fields.next().is_some() {
+        return None;
+    }
+    let (hour, minute, second) = match time {
+        Some(time) => {
+            let mut fields = time.split(':').map(|field| field.parse::<u32>().ok());
+            let (hour, minute, second) = (fields.next()??, fields.next()??, fields.next().unwrap_or(Some(0))?);
+            if fields.next().is_some() {
+                return None;
+            }
+            (hour, minute, second)
+        }
+        None => (0, 0, 0),
+    };
+    let year = i32::try_from(year).ok()?;
+    if year >= 1700 {
+        let time = NaiveDate::from_ymd_opt(year, month, day)?.and_hms_opt(hour, minute, second)?;
+        return local_to_pdate(time, zone);
+    }
+    let date = NaiveJalaliDate::from_ymd_opt(year, month, day)?;
+    if hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+    Some(PDate::from_naive_jalali(date, hour, minute, second, zone))
+}
+
+fn local_to_pdate(time : NaiveDateTime, zone : Tz) -> Option<PDate> {
+    let date = zone.from_local_datetime(&time).earliest()?;
+    in_zone(date.timestamp_millis(), zone)
+}
+
+/// Add a duration like `3d`, `-2w` or `1y6mo`, years and months are added in jalali calendar
+fn add_duration(date : &PDate, duration : &str) -> Result<PDate, String> {
+    let invalid = || format!("invalid duration '{}'", duration);
+    let normalized = normalize_digits(duration.trim());
+    let (sign, mut rest) = match normalized.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, normalized.strip_prefix('+').unwrap_or(&normalized)),
+    };
+    if rest.is_empty() {
+        return Err(invalid());
+    }
+    let (mut months, mut seconds) = (0i64, 0i64);
+    while !rest.is_empty() {
+        let digits = rest.find(|c : char| !c.is_ascii_digit()).ok_or_else(invalid)?;
+        if digits == 0 {
+            return Err(invalid());
+        }
+        // only digits are left, so parsing fails on overflow
+        let count : i64 = rest[..digits].parse().map_err(|_| OUT_OF_RANGE)?;
+        let unit_end = rest[digits..].find(|c : char| c.is_ascii_digit()).map_or(rest.len(), |end| digits + end);
+        let (total, unit) = match &rest[digits..unit_end] {
+            "y" => (&mut months, 12),
+            "mo" => (&mut months, 1),
+            "w" => (&mut seconds, 7 * 86400),
+            "d" => (&mut seconds, 86400),
+            "h" => (&mut seconds, 3600),
+            "m" => (&mut seconds, 60),
+            "s" => (&mut seconds, 1),
+            _ => return Err(invalid()),
+        };
+        *total = count.checked_mul(unit).and_then(|count| total.checked_add(count)).ok_or(OUT_OF_RANGE)?;
+        rest = &rest[unit_end..];
+    }
+    let mut date = in_zone(date.time_millis(), date.time_zone()).ok_or(OUT_OF_RANGE)?;
+    if months != 0 {
+        let index = (date.year() as i64 * 12 + date.month() as i64 - 1).checked_add(sign * months).ok_or(OUT_OF_RANGE)?;
+        let year = i32::try_from(index.div_euclid(12)).map_err(|_| OUT_OF_RANGE)?;
+        let month = index.rem_euclid(12) as u32 + 1;
+        let day = NaiveJalaliDate::from_ymd_opt(year, month, 1).ok_or(OUT_OF_RANGE)?.month_days().min(date.day());
+        let jalali = NaiveJalaliDate::from_ymd_opt(year, month, day).ok_or(OUT_OF_RANGE)?;
+        date = PDate::from_naive_jalali(jalali, date.hour(), date.minute(), date.second(), date.time_zone());
+    }
+    let millis = seconds.checked_mul(1000).and_then(|millis| date.time_millis().checked_add(sign * millis));
+    millis.and_then(|millis| in_zone(millis, date.time_zone())).ok_or(OUT_OF_RANGE.to_string())
+}
@@ -0,0 +1,295 @@
+//! Pure jalali calendar arithmetic
+//!
+//! Everything in this module works without `std`, never allocates and is usable in `const` context
+//!
+//! Dates are passed around as `(year, month, day)` tuples, and
+//! [Julian Day Number](https://en.wikipedia.org/wiki/Julian_day) is used as a pivot between calendars
+//!
+//! Both calendars are proleptic with astronomical year numbering (negative years before year 1),
+//! leap years follow the arithmetic 33-year cycle of the solar hijri calendar.
+//! Supported range is [MIN_YEAR]..=[MAX_YEAR] jalali years, unchecked functions give
+//! meaningless results outside of it and checked functions return `None`
+//!
+//! # Example
+//!
+//! ```
+//! use persian_date::calendar;
+//!
+//! assert_eq!(calendar::gregorian_to_jalali(2024, 4, 3), (1403, 1, 15));
+//! assert_eq!(calendar::jalali_to_gregorian(1403, 1, 15), (2024, 4, 3));
+//! assert_eq!(calendar::jalali_to_jdn(1403, 1, 15), 2460404);
+//! assert_eq!(calendar::days_since_epoch_to_jalali(0), (1348, 10, 11));
+//! assert_eq!(calendar::gregorian_to_jalali(1, 1, 1), (-621, 10, 11));
+//! assert_eq!(calendar::checked_jalali_to_gregorian(calendar::MAX_YEAR + 1, 1, 1), None);
+//!
+//! const NOWRUZ : (i32, u32, u32) = calendar::jalali_to_gregorian(1403, 1, 1);
+//! assert_eq!(NOWRUZ, (2024, 3, 20));
+//! ```
+
+/// Smallest supported jalali year
+///
+/// Years are astronomical: year 0 is the year before 1 and -1 the year before 0
+pub const MIN_YEAR : i32 = -200_000;
+/// Largest supported jalali year
+pub const MAX_YEAR : i32 = 200_000;
+/// Smallest supported gregorian year, the first one which is entirely after start of [MIN_YEAR]
+///
+/// Gregorian year `y` starts in jalali year `y - 622` and ends in jalali year `y - 621`
+pub const MIN_GRG_YEAR : i32 = MIN_YEAR + 622;
+/// Largest supported gregorian year, the last one which is entirely before end of [MAX_YEAR]
+pub const MAX_GRG_YEAR : i32 = MAX_YEAR + 621;
+
+/// Smallest supported hijri year, the first one which is entirely after start of [MIN_YEAR]
+pub const MIN_HIJRI_YEAR : i32 = jdn_to_hijri(MIN_JDN).0 + 1;
+/// Largest supported hijri year, the last one which is entirely before end of [MAX_YEAR]
+pub const MAX_HIJRI_YEAR : i32 = jdn_to_hijri(jalali_to_jdn(MAX_YEAR, 12, 29)).0 - 1;
+
+/// Julian Day Number of first day of [MIN_YEAR]
+pub const MIN_JDN : i64 = jalali_to_jdn(MIN_YEAR, 1, 1);
+/// Julian Day Number of last day of [MAX_YEAR]
+pub const MAX_JDN : i64 = jalali_to_jdn(MAX_YEAR, 12, month_days(MAX_YEAR, 12));
+
+/// Julian Day Number of unix epoch (1970-01-01)
+pub const UNIX_EPOCH_JDN : i64 = 2440588;
+
+/// Julian Day Number of 1 Muharram 1 (civil epoch of tabular islamic calendar)
+const HIJRI_EPOCH_JDN : i64 = 1948440;
+
+const GRG_DAYS_BEFORE_MONTH : [i32; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+
+/// Convert gregorian year, month and day to jalali `(year, month, day)`
+///
+/// Input is not validated, use [checked_gregorian_to_jalali] for unknown input
+pub const fn gregorian_to_jalali(gy : i32,gm : u32,gd : u32) -> (i32, u32, u32) {
+    let jy = if gm > 2 { gy + 1 } else { gy };
+    let mut days = 355666 + (365 * gy) + (jy + 3).div_euclid(4) - (jy + 99).div_euclid(100)
+        + (jy + 399).div_euclid(400) + gd as i32 + GRG_DAYS_BEFORE_MONTH[gm as usize - 1];
+    let mut jy = -1595 + (33 * days.div_euclid(12053));
+    days = days.rem_euclid(12053);
+    jy += 4 * (days / 1461);
+    days %= 1461;
+    if days > 365 {
+        jy += (days - 1) / 365;
+        days = (days - 1) % 365;
+    }
+    if days < 186 {
+        (jy, 1 + (days / 31) as u32, 1 + (days % 31) as u32)
+    } else {
+        (jy, 7 + ((days - 186) / 30) as u32, 1 + ((days - 186) % 30) as u32)
+    }
+}
+
+/// Convert jalali year, month and day to gregorian `(year, month, day)`
+///
+/// Input is not validated, use [checked_jalali_to_gregorian] for unknown input
+pub const fn jalali_to_gregorian(jy : i32,jm : u32,jd : u32) -> (i32, u32, u32) {
+    let jy = jy + 1595;
+    let jm = jm as i32;
+    let mut days = -355668 + (365 * jy) + (jy.div_euclid(33) * 8) + ((jy.rem_euclid(33) + 3) / 4) + jd as i32
+        + if jm < 7 {(jm - 1) * 31} else {((jm - 7) * 30) + 186};
+    let mut gy = 400 * days.div_euclid(146097);
+    days = days.rem_euclid(146097);
+    if days > 36524 {
+        days -= 1;
+        gy += 100 * (days / 36524);
+        days %= 36524;
+        if days >= 365 {
+            days += 1;
+        }
+    }
+    gy += 4 * (days / 1461);
+    days %= 1461;
+    if days > 365 {
+        gy += (days - 1) / 365;
+        days = (days - 1) % 365;
+    }
+    let sal_a = [0, 31, if is_grg_leap_year(gy) {29} else {28},
+        31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+    let mut gd = days + 1;
+    let mut gm = 0;
+    while gm < 13 && gd > sal_a[gm] {
+        gd -= sal_a[gm];
+        gm += 1;
+    }
+    (gy, gm as u32, gd as u32)
+}
+
+/// Convert gregorian date to jalali date, `None` when gregorian date not exists or is out of range
+pub const fn checked_gregorian_to_jalali(gy : i32,gm : u32,gd : u32) -> Option<(i32, u32, u32)> {
+    if gy < MIN_GRG_YEAR || gy > MAX_GRG_YEAR || !is_valid_gregorian(gy, gm, gd) {
+        return None;
+    }
+    let jalali = gregorian_to_jalali(gy, gm, gd);
+    if jalali.0 < MIN_YEAR || jalali.0 > MAX_YEAR {
+        return None;
+    }
+    Some(jalali)
+}
+
+/// Convert jalali date to gregorian date, `None` when jalali date not exists or is out of range
+pub const fn checked_jalali_to_gregorian(jy : i32,jm : u32,jd : u32) -> Option<(i32, u32, u32)> {
+    if !is_valid_jalali(jy, jm, jd) {
+        return None;
+    }
+    Some(jalali_to_gregorian(jy, jm, jd))
+}
+
+/// Convert gregorian date to Julian Day Number
+pub const fn gregorian_to_jdn(gy : i32,gm : u32,gd : u32) -> i64 {
+    let a = (14 - gm as i64) / 12;
+    let y = gy as i64 + 4800 - a;
+    let m = gm as i64 + 12 * a - 3;
+    gd as i64 + (153 * m + 2) / 5 + 365 * y + y.div_euclid(4) - y.div_euclid(100) + y.div_euclid(400) - 32045
+}
+
+/// Convert Julian Day Number to gregorian `(year, month, day)`
+pub const fn jdn_to_gregorian(jdn : i64) -> (i32, u32, u32) {
+    let a = jdn + 32044;
+    let b = (4 * a + 3).div_euclid(146097);
+    let c = a - (146097 * b).div_euclid(4);
+    let d = (4 * c + 3) / 1461;
+    let e = c - (1461 * d) / 4;
+    let m = (5 * e + 2) / 153;
+    let day = e - (153 * m + 2) / 5 + 1;
+    let month = m + 3 - 12 * (m / 10);
+    let year = 100 * b + d - 4800 + m / 10;
+    (year as i32, month as u32, day as u32)
+}
+
+/// Convert jalali date to Julian Day Number
+pub const fn jalali_to_jdn(jy : i32,jm : u32,jd : u32) -> i64 {
+    let (gy, gm, gd) = jalali_to_gregorian(jy, jm, jd);
+    gregorian_to_jdn(gy, gm, gd)
+}
+
+/// Convert Julian Day Number to jalali `(year, month, day)`
+pub const fn jdn_to_jalali(jdn : i64) -> (i32, u32, u32) {
+    let (gy, gm, gd) = jdn_to_gregorian(jdn);
+    gregorian_to_jalali(gy, gm, gd)
+}
+
+/// Convert jalali date to count of days since unix epoch (1970-01-01)
+pub const fn jalali_to_days_since_epoch(jy : i32,jm : u32,jd : u32) -> i64 {
+    jalali_to_jdn(jy, jm, jd) - UNIX_EPOCH_JDN
+}
+
+/// Convert count of days since unix epoch (1970-01-01) to jalali `(year, month, day)`
+pub const fn days_since_epoch_to_jalali(days : i64) -> (i32, u32, u32) {
+    jdn_to_jalali(days + UNIX_EPOCH_JDN)
+}
+
+/// Convert hijri (lunar) date to Julian Day Number
+///
+/// Hijri dates follow the tabular (arithmetic) islamic calendar,
+/// which may differ one or two days from the officially observed calendar
+pub const fn hijri_to_jdn(hy : i32,hm : u32,hd : u32) -> i64 {
+    let hy = hy as i64;
+    hd as i64 + (59 * (hm as i64 - 1) + 1) / 2 + (hy - 1) * 354 + (3 + 11 * hy).div_euclid(30) + HIJRI_EPOCH_JDN - 1
+}
+
+/// Convert Julian Day Number to hijri (lunar) `(year, month, day)`
+pub const fn jdn_to_hijri(jdn : i64) -> (i32, u32, u32) {
+    let year = (30 * (jdn - HIJRI_EPOCH_JDN) + 10646).div_euclid(10631);
+    let days = jdn - hijri_to_jdn(year as i32, 1, 1);
+    let month = (2 * days + 59).div_euclid(59);
+    let month = if month > 12 { 12 } else { month };
+    let day = jdn - hijri_to_jdn(year as i32, month as u32, 1) + 1;
+    (year as i32, month as u32, day as u32)
+}
+
+/// Return count of days in hijri (lunar) month, 29 or 30 in tabular islamic calendar
+pub const fn hijri_month_days(year : i32,month : u32) -> u32 {
+    let next = if month == 12 { hijri_to_jdn(year + 1, 1, 1) } else { hijri_to_jdn(year, month + 1, 1) };
+    (next - hijri_to_jdn(year, month, 1)) as u32
+}
+
+/// Convert jalali date to hijri (lunar) `(year, month, day)`
+pub const fn jalali_to_hijri(jy : i32,jm : u32,jd : u32) -> (i32, u32, u32) {
+    jdn_to_hijri(jalali_to_jdn(jy, jm, jd))
+}
+
+/// Convert hijri (lunar) date to jalali `(year, month, day)`
+pub const fn hijri_to_jalali(hy : i32,hm : u32,hd : u32) -> (i32, u32, u32) {
+    jdn_to_jalali(hijri_to_jdn(hy, hm, hd))
+}
+
+/// Convert gregorian date to hijri (lunar) `(year, month, day)`
+pub const fn gregorian_to_hijri(gy : i32,gm : u32,gd : u32) -> (i32, u32, u32) {
+    jdn_to_hijri(gregorian_to_jdn(gy, gm, gd))
+}
+
+/// Return jalali date exists (and is in supported range) or not
+pub const fn is_valid_jalali(year : i32,month : u32,day : u32) -> bool {
+    if year < MIN_YEAR || year > MAX_YEAR || month < 1 || month > 12 || day < 1 || day > 31 {
+        return false;
+    }
+    let (gy, gm, gd) = jalali_to_gregorian(year, month, day);
+    let (jy, jm, jd) = gregorian_to_jalali(gy, gm, gd);
+    jy == year && jm == month && jd == day
+}
+
+/// Return gregorian date exists or not
+pub const fn is_valid_gregorian(year : i32,month : u32,day : u32) -> bool {
+    if month < 1 || month > 12 || day < 1 {
+        return false;
+    }
+    let days = match month {
+        2 => if is_grg_leap_year(year) { 29 } else { 28 },
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    };
+    day <= days
+}
+
+/// Return jalali year is leap (esfand has 30 days) or not
+pub const fn is_leap_year(year : i32) -> bool {
+    is_valid_jalali(year, 12, 30)
+}
+
+/// Return gregorian year is leap or not
+pub const fn is_grg_leap_year(year : i32) -> bool {
+    (year.rem_euclid(4) == 0 && year.rem_euclid(100) != 0) || year.rem_euclid(400) == 0
+}
+
+/// Return count of days in jalali month
+pub const fn month_days(year : i32,month : u32) -> u32 {
+    if month <= 6 {
+        31
+    } else if month <= 11 || is_leap_year(year) {
+        30
+    } else {
+        29
+    }
+}
+
+/// Return day in jalali year
+pub const fn day_of_year(month : u32,day : u32) -> u32 {
+    if month <= 7 {
+        (month - 1) * 31 + day
+    } else {
+        186 + (month - 7) * 30 + day
+    }
+}
+
+/// Convert jalali date to zoroastrian fasli `(year, month, day)`
+///
+/// Fasli year starts with jalali year and has twelve 30 day months,
+/// then five gatha days (six in leap years) returned as month 13
+pub const fn jalali_to_fasli(jy : i32,jm : u32,jd : u32) -> (i32, u32, u32) {
+    let day_of_year = day_of_year(jm, jd);
+    if day_of_year > 360 {
+        (jy, 13, day_of_year - 360)
+    } else {
+        (jy, (day_of_year - 1) / 30 + 1, (day_of_year - 1) % 30 + 1)
+    }
+}
+
+/// Convert zoroastrian fasli date to jalali `(year, month, day)`
+pub const fn fasli_to_jalali(fy : i32,fm : u32,fd : u32) -> (i32, u32, u32) {
+    let day_of_year = (fm - 1) * 30 + fd;
+    if day_of_year <= 186 {
+        (fy, (day_of_year - 1) / 31 + 1, (day_of_year - 1) % 31 + 1)
+    } else {
+        (fy, (day_of_year - 187) / 30 + 7, (day_of_year - 187) % 30 + 1)
+    }
+}
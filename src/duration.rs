@@ -0,0 +1,218 @@
+//! Human friendly durations, like `۲ ساعت و ۱۵ دقیقه` or `۱ سال و ۳ ماه`
+//!
+//! Works with [chrono::TimeDelta] for elapsed times and with [CalendarDiff]
+//! for distances in jalali years, months and days
+//!
+//! # Example
+//!
+//! ```
+//! use chrono::TimeDelta;
+//! use persian_date::duration::{DurationOptions, FormatDuration, Rounding};
+//! use persian_date::locale::Locale;
+//! use persian_date::structure::NaiveJalaliDate;
+//!
+//! let elapsed = TimeDelta::minutes(135) + TimeDelta::seconds(40);
+//! assert_eq!(elapsed.format_duration(&DurationOptions::default()), "۲ ساعت و ۱۵ دقیقه");
+//! assert_eq!(elapsed.format_duration(&DurationOptions { short: true, ..Default::default() }), "۲ س ۱۵ د");
+//! assert_eq!(elapsed.format_duration(&DurationOptions { rounding: Rounding::Round, ..Default::default() }), "۲ ساعت و ۱۶ دقیقه");
+//! assert_eq!(elapsed.format_duration(&DurationOptions { precision: 3, locale: Locale::English, ..Default::default() }), "2 hours, 15 minutes, 40 seconds");
+//!
+//! let start = NaiveJalaliDate::from_ymd_opt(1402, 10, 1).unwrap();
+//! let end = NaiveJalaliDate::from_ymd_opt(1404, 1, 20).unwrap();
+//! let diff = end.calendar_diff(&start);
+//! assert_eq!((diff.years, diff.months, diff.days), (1, 3, 19));
+//! assert_eq!(diff.format_duration(&DurationOptions::default()), "۱ سال و ۳ ماه");
+//! ```
+
+use core::fmt::Write;
+use alloc::string::String;
+use chrono::TimeDelta;
+use crate::calendar;
+use crate::locale::{Digits, DigitsWriter, Locale};
+use crate::structure::NaiveJalaliDate;
+#[cfg(feature = "tz")]
+use crate::structure::{PDate, Setter};
+
+/// Distance between two dates in jalali calendar units
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct CalendarDiff {
+    /// Distance is backward in time
+    pub negative : bool,
+    pub years : u32,
+    pub months : u32,
+    pub days : u32,
+    pub hours : u32,
+    pub minutes : u32,
+    pub seconds : u32,
+}
+
+/// Rounding of the last written unit
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Rounding {
+    /// Drop smaller units
+    #[default]
+    Floor,
+    /// Round half up by the next smaller unit
+    Round,
+}
+
+/// Options of [FormatDuration]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DurationOptions {
+    /// Language of output, [Locale::English] writes english and other locales persian
+    pub locale : Locale,
+    /// Digits of numbers, default is digits of locale
+    pub digits : Option<Digits>,
+    /// Count of largest non-zero units written, default 2
+    pub precision : usize,
+    pub rounding : Rounding,
+    /// Short unit names, like `۲ س ۱۵ د` or `2h 15m`
+    pub short : bool,
+}
+
+impl Default for DurationOptions {
+    fn default() -> Self {
+        DurationOptions { locale: Locale::Persian, digits: None, precision: 2, rounding: Rounding::Floor, short: false }
+    }
+}
+
+/// Formatting of durations in words
+pub trait FormatDuration {
+    fn format_duration(&self, options : &DurationOptions) -> String;
+}
+
+/// Units from years to seconds
+const UNITS : usize = 6;
+/// Size of each unit in the next smaller one, used for rounding and carry (days carry is not fixed)
+const UNIT_LIMITS : [u64; UNITS] = [u64::MAX, 12, 30, 24, 60, 60];
+const PERSIAN_NAMES : [&str; UNITS] = ["سال", "ماه", "روز", "ساعت", "دقیقه", "ثانیه"];
+const PERSIAN_SHORT_NAMES : [&str; UNITS] = ["سال", "م", "ر", "س", "د", "ث"];
+const ENGLISH_NAMES : [&str; UNITS] = ["year", "month", "day", "hour", "minute", "second"];
+const ENGLISH_SHORT_NAMES : [&str; UNITS] = ["y", "mo", "d", "h", "m", "s"];
+
+fn format_units(negative : bool, mut values : [u64; UNITS], options : &DurationOptions) -> String {
+    let first = values.iter().position(|value| *value > 0).unwrap_or(UNITS - 1);
+    let last = (first + options.precision.max(1) - 1).min(UNITS - 1);
+    if options.rounding == Rounding::Round && last + 1 < UNITS && values[last + 1] * 2 >= UNIT_LIMITS[last + 1] {
+        values[last] += 1;
+        // carry to larger units, except days which have no fixed size in months
+        let mut index = last;
+        while index > 0 && index != 2 && values[index] >= UNIT_LIMITS[index] {
+            values[index] -= UNIT_LIMITS[index];
+            values[index - 1] += 1;
+            index -= 1;
+        }
+    }
+    let first = values.iter().position(|value| *value > 0).unwrap_or(UNITS - 1);
+    let last = last.min((first + options.precision.max(1) - 1).min(UNITS - 1));
+    let english = options.locale == Locale::English;
+    let names = match (english, options.short) {
+        (false, false) => &PERSIAN_NAMES,
+        (false, true) => &PERSIAN_SHORT_NAMES,
+        (true, false) => &ENGLISH_NAMES,
+        (true, true) => &ENGLISH_SHORT_NAMES,
+    };
+    let separator = match (english, options.short) {
+        (_, true) => " ",
+        (false, false) => " و ",
+        (true, false) => ", ",
+    };
+    let mut out = String::new();
+    let mut f = DigitsWriter { inner: &mut out, digits: options.digits.unwrap_or(options.locale.digits()) };
+    if negative {
+        let _ = f.write_str(if english || options.short { "-" } else { "منفی " });
+    }
+    let mut written = false;
+    for index in first..=last {
+        if values[index] == 0 && (written || index < last) {
+            continue;
+        }
+        if written {
+            let _ = f.write_str(separator);
+        }
+        let plural = if english && !options.short && values[index] != 1 { "s" } else { "" };
+        let _ = match (english, options.short) {
+            (true, true) => write!(f,"{}{}",values[index],names[index]),
+            _ => write!(f,"{} {}{}",values[index],names[index],plural),
+        };
+        written = true;
+    }
+    out
+}
+
+impl FormatDuration for TimeDelta {
+    fn format_duration(&self, options : &DurationOptions) -> String {
+        let seconds = self.num_seconds().unsigned_abs();
+        let values = [0, 0, seconds / 86400, seconds / 3600 % 24, seconds / 60 % 60, seconds % 60];
+        format_units(self.num_seconds() < 0, values, options)
+    }
+}
+
+impl FormatDuration for CalendarDiff {
+    fn format_duration(&self, options : &DurationOptions) -> String {
+        let values = [self.years, self.months, self.days, self.hours, self.minutes, self.seconds].map(u64::from);
+        format_units(self.negative, values, options)
+    }
+}
+
+/// Return difference of dates `(years, months, days)`, `from` must not be after `to`
+fn date_diff(from : (i32, u32, u32), to : (i32, u32, u32)) -> (u32, u32, u32) {
+    let mut years = to.0 - from.0;
+    let mut months = to.1 as i32 - from.1 as i32;
+    let mut days = to.2 as i32 - from.2 as i32;
+    if days < 0 {
+        months -= 1;
+        let (year, month) = if to.1 == 1 { (to.0 - 1, 12) } else { (to.0, to.1 - 1) };
+        days += calendar::month_days(year, month) as i32;
+    }
+    if months < 0 {
+        years -= 1;
+        months += 12;
+    }
+    (years as u32, months as u32, days.max(0) as u32)
+}
+
+impl NaiveJalaliDate {
+    /// Return distance from other date to this date in jalali years, months and days
+    pub fn calendar_diff(&self, other : &NaiveJalaliDate) -> CalendarDiff {
+        let negative = self < other;
+        let (from, to) = if negative { (self, other) } else { (other, self) };
+        let (years, months, days) = date_diff((from.year, from.month, from.day), (to.year, to.month, to.day));
+        CalendarDiff { negative, years, months, days, ..Default::default() }
+    }
+}
+
+#[cfg(feature = "tz")]
+impl PDate {
+    /// Return distance from other date to this date in jalali units, both dates are read in time zone of this date
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use persian_date::duration::{DurationOptions, FormatDuration};
+    /// use persian_date::jdatetime;
+    ///
+    /// let diff = jdatetime!(1403-01-15 09:30:00).calendar_diff(&jdatetime!(1402-12-20 18:00:00));
+    /// assert_eq!((diff.months, diff.days, diff.hours, diff.minutes), (0, 23, 15, 30));
+    /// assert_eq!(diff.format_duration(&DurationOptions { precision: 3, ..Default::default() }), "۲۳ روز و ۱۵ ساعت و ۳۰ دقیقه");
+    /// ```
+    pub fn calendar_diff(&self, other : &PDate) -> CalendarDiff {
+        let negative = self.time_millis < other.time_millis;
+        let (from, to) = if negative { (self.time_millis, other.time_millis) } else { (other.time_millis, self.time_millis) };
+        let local = |millis : i64| {
+            let mut date = PDate::from_time_millis(millis);
+            date.set_time_zone(self.time_zone);
+            date
+        };
+        let (from, to) = (local(from), local(to));
+        let mut to_date = to.jalali_date();
+        let mut seconds = (to.hour * 3600 + to.minute * 60 + to.second) as i64 - (from.hour * 3600 + from.minute * 60 + from.second) as i64;
+        if seconds < 0 {
+            seconds += 86400;
+            to_date = NaiveJalaliDate::from_jdn(to_date.to_jdn() - 1);
+        }
+        let (years, months, days) = date_diff((from.year, from.month, from.day), (to_date.year, to_date.month, to_date.day));
+        let seconds = seconds as u32;
+        CalendarDiff { negative, years, months, days, hours: seconds / 3600, minutes: seconds / 60 % 60, seconds: seconds % 60 }
+    }
+}
@@ -0,0 +1,86 @@
+//! Alternative year numbering of the solar hijri calendar
+//!
+//! Eras reuse the jalali months and days and only shift the year number,
+//! zoroastrian dates also name each day of month after an avestan yazata
+//!
+//! | Era | Year of 1403 | Note |
+//! |-----|--------------|------|
+//! | [Era::SolarHijri] | 1403 | |
+//! | [Era::Kurdish] | 2724 | solar hijri + 1321 |
+//! | [Era::Imperial] | 2583 | shahanshahi era, official in 1355 to 1357 (2535 to 2537) |
+//! | [Era::Zoroastrian] | 3762 | zoroastrian religious era, solar hijri + 2359 |
+//!
+//! # Example
+//!
+//! ```
+//! use persian_date::era::{self, Era};
+//!
+//! assert_eq!(Era::Imperial.from_jalali_year(1355), 2535);
+//! assert_eq!(Era::Kurdish.to_jalali_year(2724), 1403);
+//! assert_eq!(era::fasli_day_name(1, 1), "هرمزد");
+//! assert_eq!(era::fasli_day_name(13, 1), "اهنود");
+//! ```
+
+/// Year numbering of the solar hijri calendar
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum Era {
+    /// Solar hijri (jalali) era
+    #[default]
+    SolarHijri,
+    /// Kurdish era (solar hijri + 1321)
+    Kurdish,
+    /// Imperial (shahanshahi) era (solar hijri + 1180)
+    Imperial,
+    /// Zoroastrian religious era (solar hijri + 2359)
+    Zoroastrian,
+}
+
+pub(crate) const FASLI_DAY_NAMES : [&str; 30] = ["هرمزد", "بهمن", "اردیبهشت", "شهریور", "سپندارمذ", "خرداد", "امرداد", "دی‌به‌آذر", "آذر", "آبان",
+    "خور", "ماه", "تیر", "گوش", "دی‌به‌مهر", "مهر", "سروش", "رشن", "فروردین", "ورهرام",
+    "رام", "باد", "دی‌به‌دین", "دین", "ارد", "اشتاد", "آسمان", "زامیاد", "مانتره‌سپند", "انارام"];
+pub(crate) const GATHA_DAY_NAMES : [&str; 6] = ["اهنود", "اشتود", "سپنتمد", "وهوخشتر", "وهیشتوایشت", "اورداد"];
+
+impl Era {
+    /// Return count of years added to solar hijri year
+    pub const fn offset(&self) -> i32 {
+        match self {
+            Era::SolarHijri => 0,
+            Era::Kurdish => 1321,
+            Era::Imperial => 1180,
+            Era::Zoroastrian => 2359,
+        }
+    }
+
+    /// Convert jalali year to year of this era
+    pub const fn from_jalali_year(&self, year : i32) -> i32 {
+        year + self.offset()
+    }
+
+    /// Convert year of this era to jalali year
+    pub const fn to_jalali_year(&self, year : i32) -> i32 {
+        year - self.offset()
+    }
+
+    /// Return persian name of era
+    pub fn name(&self) -> &'static str {
+        match self {
+            Era::SolarHijri => "هجری شمسی",
+            Era::Kurdish => "کوردی",
+            Era::Imperial => "شاهنشاهی",
+            Era::Zoroastrian => "زرتشتی",
+        }
+    }
+}
+
+/// Name of the gatha days at end of fasli year
+pub(crate) const GATHA_NAME : &str = "گاتاها";
+
+/// Return name of fasli month (1 to 12, 13 for gatha days)
+pub fn fasli_month_name(month : u32) -> &'static str {
+    if month == 13 { GATHA_NAME } else { crate::locale::PERSIAN_MONTH_NAMES[(month - 1) as usize] }
+}
+
+/// Return avestan name of fasli day (month 13 for gatha days)
+pub fn fasli_day_name(month : u32, day : u32) -> &'static str {
+    if month == 13 { GATHA_DAY_NAMES[(day - 1) as usize] } else { FASLI_DAY_NAMES[(day - 1) as usize] }
+}
@@ -1,9 +1,97 @@
-use std::fmt::{Debug, Display, Formatter};
+use core::fmt::{Debug, Display, Formatter};
+use core::fmt::Write;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "tz")]
+use alloc::string::ToString;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "tz")]
+use chrono::{Datelike, Offset};
+#[cfg(feature = "tz")]
+use crate::bidi::{BidiFormat, BidiMark};
+use crate::era::Era;
+#[cfg(feature = "tz")]
+use crate::{era, words};
+use crate::locale::{DigitsWriter, Locale};
+use crate::structure::NaiveJalaliDate;
+#[cfg(feature = "tz")]
 use crate::structure::{PDate, Reader};
 
 /// Formatting Trait for Format date to string
 /// Syntax like chrono formatting
 ///
+/// | Directive | Meaning |
+/// |-----------|---------|
+/// | `%Y` | jalali year |
+/// | `%C` | year divided by 100, zero padded to 2 digits |
+/// | `%y` | year modulo 100, zero padded to 2 digits |
+/// | `%m` | month, zero padded |
+/// | `%B` | month name |
+/// | `%d` | day, zero padded |
+/// | `%e` | day, space padded |
+/// | `%A` | week day name |
+/// | `%w` | day in week (saturday is 0) |
+/// | `%U` | week of year, zero padded |
+/// | `%j` | day of year, zero padded to 3 digits |
+/// | `%H` / `%k` | hour, zero / space padded |
+/// | `%I` / `%l` | hour in 12 time format (01-12), zero / space padded |
+/// | `%P` / `%p` | time of day, long / short |
+/// | `%M` | minute, zero padded |
+/// | `%S` | second, zero padded |
+/// | `%f` | nanoseconds since last whole second, zero padded to 9 digits |
+/// | `%3f` | milliseconds since last whole second, zero padded to 3 digits |
+/// | `%.f` | fraction of second with leading dot, 3, 6 or 9 digits, nothing for whole seconds (like chrono) |
+/// | `%a` | short week day name (one letter) |
+/// | `%:z` | timezone offset like `+03:30` |
+/// | `%z` | timezone offset like `+0330` |
+/// | `%s` | unix timestamp in seconds |
+/// | `%%` | literal `%` |
+///
+/// Padding and case of a directive can be changed with modifiers (like GNU date and chrono)
+///
+/// | Modifier | Meaning | Example |
+/// |----------|---------|---------|
+/// | `-` | no padding | `%-d` : `5` |
+/// | `_` | pad with spaces | `%_m` : ` 1` |
+/// | `0` | pad with zeros | `%0e` : `05` |
+/// | `^` | uppercase names | `%^gB` : `APRIL` |
+///
+/// Gregorian and hijri (lunar) fields use `%g` and `%h` prefixes so one pattern can mix calendars
+///
+/// | Directive | Meaning |
+/// |-----------|---------|
+/// | `%gY` / `%hY` | gregorian / hijri year |
+/// | `%gy` | gregorian year modulo 100, zero padded to 2 digits |
+/// | `%gm` / `%hm` | gregorian / hijri month, zero padded |
+/// | `%gB` / `%hB` | gregorian / hijri month name |
+/// | `%gb` | short gregorian month name like `Apr` |
+/// | `%gd` / `%hd` | gregorian / hijri day, zero padded |
+/// | `%ge` / `%he` | gregorian / hijri day, space padded |
+/// | `%gA` | gregorian week day name |
+/// | `%gw` | day in gregorian week (sunday is 0) |
+/// | `%gj` | day of gregorian year, zero padded to 3 digits |
+///
+/// Alternative eras and the zoroastrian fasli calendar use `%E` prefix, see [era](crate::era)
+///
+/// | Directive | Meaning |
+/// |-----------|---------|
+/// | `%EK` / `%EI` / `%EZ` | year in kurdish / imperial / zoroastrian era |
+/// | `%Em` | fasli month, zero padded (13 for gatha days) |
+/// | `%EB` | fasli month name |
+/// | `%Ed` | fasli day, zero padded |
+/// | `%EA` | avestan name of fasli day |
+///
+/// Dates of legal documents are written in words with `%O` prefix, see [words](crate::words)
+///
+/// | Directive | Meaning |
+/// |-----------|---------|
+/// | `%OY` | year in words, like `یک هزار و چهارصد و سه` |
+/// | `%Od` | ordinal day in words, like `پانزدهم` |
+/// | `%OB` | month name with `ماه`, like `فروردین ماه` |
+///
+/// Unknown directives are written as is
+///
 /// # Example
 ///
 /// ```
@@ -11,50 +99,734 @@ use crate::structure::{PDate, Reader};
 /// use persian_date::format::Format;
 ///
 /// let pdate = PDate::now();
-/// println!("{}",pdate.format("%Y-%m-%d %H:%M:%S"))
-/// // print like : 1403-3-12 12:20:45
+/// println!("{}",pdate.format("%Y-%m-%d %H:%M:%S"));
+/// // print like : 1403-03-12 12:20:45
+///
+/// let pdate = PDate::from_time_millis(1712136600000);
+/// assert_eq!(pdate.format("%d %B %Y برابر با %gd %gB %gY"), "15 فروردین 1403 برابر با 03 April 2024");
+/// assert_eq!(pdate.format("%hd %hB %hY"), "24 رمضان 1445");
+/// assert_eq!(pdate.format("%Y/%-m/%-d"), "1403/1/15");
+/// assert_eq!(pdate.format("%H:%M:%S%.f"), "13:00:00");
+/// assert_eq!(PDate::from_time_millis(1712136600250).format("%H:%M:%S%.f"), "13:00:00.250");
+/// assert_eq!(pdate.format("%-gd %^gB %gY"), "3 APRIL 2024");
+/// assert_eq!(pdate.format("%Od %OB %OY"), "پانزدهم فروردین ماه یک هزار و چهارصد و سه");
+/// assert_eq!(pdate.format("%EK کوردی، روز %EA"), "2724 کوردی، روز دی‌به‌مهر");
 /// ```
 ///
+#[cfg(feature = "alloc")]
 pub trait Format {
     fn format(&self, pattern : &str) -> String;
 }
 
+/// Date field which a format directive refers to
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Directive {
+    /// `%Y`
+    Year,
+    /// `%C`
+    Century,
+    /// `%y`
+    YearMod100,
+    /// `%m`
+    Month,
+    /// `%B`
+    MonthName,
+    /// `%d`
+    Day,
+    /// `%e`
+    DaySpacePadded,
+    /// `%A`
+    DayName,
+    /// `%w`
+    DayOfWeek,
+    /// `%U`
+    WeekOfYear,
+    /// `%j`
+    DayOfYear,
+    /// `%H`
+    Hour,
+    /// `%k`
+    HourSpacePadded,
+    /// `%I`
+    Hour12,
+    /// `%l`
+    Hour12SpacePadded,
+    /// `%P`
+    TimeOfDay,
+    /// `%p`
+    ShortTimeOfDay,
+    /// `%M`
+    Minute,
+    /// `%S`
+    Second,
+    /// `%f`
+    Nanosecond,
+    /// `%.f`
+    SecondFraction,
+    /// `%:z`
+    TimeZone,
+    /// `%gY`
+    GrgYear,
+    /// `%gy`
+    GrgYearMod100,
+    /// `%gm`
+    GrgMonth,
+    /// `%gB`
+    GrgMonthName,
+    /// `%gd`
+    GrgDay,
+    /// `%ge`
+    GrgDaySpacePadded,
+    /// `%gA`
+    GrgDayName,
+    /// `%hY`
+    HijriYear,
+    /// `%hm`
+    HijriMonth,
+    /// `%hB`
+    HijriMonthName,
+    /// `%hd`
+    HijriDay,
+    /// `%he`
+    HijriDaySpacePadded,
+    /// `%a`
+    ShortDayName,
+    /// `%3f`
+    Millisecond,
+    /// `%z`
+    TimeZoneCompact,
+    /// `%s`
+    Timestamp,
+    /// Timestamp milliseconds, moment `x` (no strftime directive)
+    TimestampMillis,
+    /// `%gb`
+    GrgShortMonthName,
+    /// `%gw`
+    GrgDayOfWeek,
+    /// `%gj`
+    GrgDayOfYear,
+    /// `%EK`, `%EI` and `%EZ`
+    EraYear(Era),
+    /// `%Em`
+    FasliMonth,
+    /// `%EB`
+    FasliMonthName,
+    /// `%Ed`
+    FasliDay,
+    /// `%EA`
+    FasliDayName,
+    /// `%OY`
+    YearInWords,
+    /// `%Od`
+    DayOrdinal,
+    /// `%OB`
+    MonthNameWithMah,
+    /// Short month name, moment `jMMM` (no strftime directive)
+    ShortMonthName,
+    /// Shortest week day name, moment `dd` (no strftime directive)
+    MinDayName,
+    /// Ordinal gregorian day in words, moment `Do` (no strftime directive)
+    GrgDayOrdinal,
+}
+
+/// Padding of numeric directive
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Pad {
+    /// Padding of the directive itself
+    #[default]
+    Default,
+    /// `-` : no padding
+    None,
+    /// `_` : pad with spaces
+    Space,
+    /// `0` : pad with zeros
+    Zero,
+}
+
+/// GNU/chrono style modifiers written between `%` and the directive, like `%-d` or `%^B`
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Modifier {
+    /// Padding of numbers
+    pub pad : Pad,
+    /// `^` : uppercase names
+    pub uppercase : bool,
+}
+
+/// A piece of format pattern
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Item<'a> {
+    /// Text written as is
+    Literal(&'a str),
+    /// Date field
+    Directive(Directive, Modifier),
+}
+
+/// Single-pass tokenizer of format pattern, iterates over [Item]s without allocation
+///
+/// # Example
+///
+/// ```
+/// use persian_date::format::{Directive, Item, Modifier, Pad, StrftimeItems};
+///
+/// let items : Vec<Item> = StrftimeItems::new("%Y/%-m 100%%").collect();
+/// assert_eq!(items, [
+///     Item::Directive(Directive::Year, Modifier::default()),
+///     Item::Literal("/"),
+///     Item::Directive(Directive::Month, Modifier { pad: Pad::None, uppercase: false }),
+///     Item::Literal(" 100"),
+///     Item::Literal("%"),
+/// ]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct StrftimeItems<'a> {
+    remainder : &'a str,
+}
+
+impl<'a> StrftimeItems<'a> {
+    pub fn new(pattern : &'a str) -> Self {
+        StrftimeItems { remainder: pattern }
+    }
+}
+
+impl<'a> Iterator for StrftimeItems<'a> {
+    type Item = Item<'a>;
+
+    fn next(&mut self) -> Option<Item<'a>> {
+        if self.remainder.is_empty() {
+            return None;
+        }
+        if !self.remainder.starts_with('%') {
+            let end = self.remainder.find('%').unwrap_or(self.remainder.len());
+            let (literal, remainder) = self.remainder.split_at(end);
+            self.remainder = remainder;
+            return Some(Item::Literal(literal));
+        }
+        let spec = &self.remainder[1..];
+        if let Some(remainder) = spec.strip_prefix('%') {
+            self.remainder = remainder;
+            return Some(Item::Literal("%"));
+        }
+        let bytes = spec.as_bytes();
+        let mut modifier = Modifier::default();
+        let mut start = 0;
+        while let Some(c) = bytes.get(start) {
+            match c {
+                b'-' => modifier.pad = Pad::None,
+                b'_' => modifier.pad = Pad::Space,
+                b'0' => modifier.pad = Pad::Zero,
+                b'^' => modifier.uppercase = true,
+                _ => break,
+            }
+            start += 1;
+        }
+        let (directive, len) = match &bytes[start..] {
+            [b':', b'z', ..] => (Some(Directive::TimeZone), 2),
+            [b'.', b'f', ..] => (Some(Directive::SecondFraction), 2),
+            [b'3', b'f', ..] => (Some(Directive::Millisecond), 2),
+            [b'g', c, ..] if grg_directive_of(*c).is_some() => (grg_directive_of(*c), 2),
+            [b'h', c, ..] if hijri_directive_of(*c).is_some() => (hijri_directive_of(*c), 2),
+            [b'E', c, ..] if era_directive_of(*c).is_some() => (era_directive_of(*c), 2),
+            [b'O', b'Y', ..] => (Some(Directive::YearInWords), 2),
+            [b'O', b'd', ..] => (Some(Directive::DayOrdinal), 2),
+            [b'O', b'B', ..] => (Some(Directive::MonthNameWithMah), 2),
+            [c, ..] => (directive_of(*c), 1),
+            [] => (None, 0),
+        };
+        match directive {
+            Some(directive) => {
+                self.remainder = &spec[start + len..];
+                Some(Item::Directive(directive, modifier))
+            }
+            // unknown directive, keep it as literal
+            None => {
+                let len = spec[start..].chars().next().map_or(0, char::len_utf8);
+                let (literal, remainder) = self.remainder.split_at(1 + start + len);
+                self.remainder = remainder;
+                Some(Item::Literal(literal))
+            }
+        }
+    }
+}
+
+fn directive_of(c : u8) -> Option<Directive> {
+    Some(match c {
+        b'Y' => Directive::Year,
+        b'C' => Directive::Century,
+        b'y' => Directive::YearMod100,
+        b'm' => Directive::Month,
+        b'B' => Directive::MonthName,
+        b'd' => Directive::Day,
+        b'e' => Directive::DaySpacePadded,
+        b'A' => Directive::DayName,
+        b'w' => Directive::DayOfWeek,
+        b'U' => Directive::WeekOfYear,
+        b'j' => Directive::DayOfYear,
+        b'H' => Directive::Hour,
+        b'k' => Directive::HourSpacePadded,
+        b'I' => Directive::Hour12,
+        b'l' => Directive::Hour12SpacePadded,
+        b'P' => Directive::TimeOfDay,
+        b'p' => Directive::ShortTimeOfDay,
+        b'M' => Directive::Minute,
+        b'S' => Directive::Second,
+        b'f' => Directive::Nanosecond,
+        b'a' => Directive::ShortDayName,
+        b'z' => Directive::TimeZoneCompact,
+        b's' => Directive::Timestamp,
+        _ => return None,
+    })
+}
+
+fn grg_directive_of(c : u8) -> Option<Directive> {
+    Some(match c {
+        b'Y' => Directive::GrgYear,
+        b'y' => Directive::GrgYearMod100,
+        b'm' => Directive::GrgMonth,
+        b'B' => Directive::GrgMonthName,
+        b'd' => Directive::GrgDay,
+        b'e' => Directive::GrgDaySpacePadded,
+        b'A' => Directive::GrgDayName,
+        b'b' => Directive::GrgShortMonthName,
+        b'w' => Directive::GrgDayOfWeek,
+        b'j' => Directive::GrgDayOfYear,
+        _ => return None,
+    })
+}
+
+fn hijri_directive_of(c : u8) -> Option<Directive> {
+    Some(match c {
+        b'Y' => Directive::HijriYear,
+        b'm' => Directive::HijriMonth,
+        b'B' => Directive::HijriMonthName,
+        b'd' => Directive::HijriDay,
+        b'e' => Directive::HijriDaySpacePadded,
+        _ => return None,
+    })
+}
+
+fn era_directive_of(c : u8) -> Option<Directive> {
+    Some(match c {
+        b'K' => Directive::EraYear(Era::Kurdish),
+        b'I' => Directive::EraYear(Era::Imperial),
+        b'Z' => Directive::EraYear(Era::Zoroastrian),
+        b'm' => Directive::FasliMonth,
+        b'B' => Directive::FasliMonthName,
+        b'd' => Directive::FasliDay,
+        b'A' => Directive::FasliDayName,
+        _ => return None,
+    })
+}
+
+/// Precompiled format pattern, parse once and reuse for many dates
+///
+/// # Example
+///
+/// ```
+/// use persian_date::structure::PDate;
+/// use persian_date::format::FormatItems;
+///
+/// let items = FormatItems::parse("%Y/%m/%d");
+/// let pdate = PDate::from_time_millis(1712136600000);
+/// assert_eq!(pdate.format_with_items(&items).to_string(), "1403/01/15");
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct FormatItems<'a> {
+    items : Vec<Item<'a>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> FormatItems<'a> {
+    pub fn parse(pattern : &'a str) -> Self {
+        FormatItems { items: StrftimeItems::new(pattern).collect() }
+    }
+
+    pub fn iter(&self) -> core::iter::Copied<core::slice::Iter<'_, Item<'a>>> {
+        self.items.iter().copied()
+    }
+}
+
+/// Lazily formatted date, written on [Display] without intermediate strings
+#[cfg(feature = "tz")]
+#[derive(Clone)]
+pub struct DelayedFormat<'a, I> {
+    date : &'a PDate,
+    items : I,
+    /// Locale of names, `None` keeps persian jalali and english gregorian names
+    locale : Option<Locale>,
+}
+
+#[cfg(feature = "tz")]
+impl<'a, I> DelayedFormat<'a, I> {
+    pub(crate) fn new(date : &'a PDate, items : I) -> Self {
+        DelayedFormat { date, items, locale: None }
+    }
+
+    pub(crate) fn new_localized(date : &'a PDate, items : I, locale : Locale) -> Self {
+        DelayedFormat { date, items, locale: Some(locale) }
+    }
+}
+
+#[cfg(feature = "tz")]
+impl<'a, 'b, I : Iterator<Item = Item<'b>> + Clone> Display for DelayedFormat<'a, I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        for item in self.items.clone() {
+            match item {
+                Item::Literal(literal) => f.write_str(literal)?,
+                Item::Directive(directive, modifier) => self.date.write_directive(f, directive, modifier, self.locale)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "tz")]
+impl PDate {
+    /// Format lazily, the result can be written into any [core::fmt::Write]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::fmt::Write;
+    /// use persian_date::structure::PDate;
+    ///
+    /// let pdate = PDate::from_time_millis(1712136600000);
+    /// let mut out = String::new();
+    /// write!(out, "{}", pdate.format_lazy("%d %B %Y")).unwrap();
+    /// assert_eq!(out, "15 فروردین 1403");
+    /// ```
+    pub fn format_lazy<'a>(&'a self, pattern : &'a str) -> DelayedFormat<'a, StrftimeItems<'a>> {
+        DelayedFormat::new(self, StrftimeItems::new(pattern))
+    }
+
+    /// Format lazily with month and day names of a locale, numbers are written in latin digits
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use persian_date::locale::Locale;
+    /// use persian_date::structure::PDate;
+    ///
+    /// let pdate = PDate::from_time_millis(1712136600000);
+    /// assert_eq!(pdate.format_localized("%A %d %B %Y", Locale::Dari).to_string(), "چهارشنبه 15 حمل 1403");
+    /// assert_eq!(pdate.format_localized("%A %d %B %Y", Locale::English).to_string(), "Wednesday 15 Farvardin 1403");
+    /// assert_eq!(pdate.format_localized("%gA %gd %gB", Locale::Persian).to_string(), "چهارشنبه 03 آوریل");
+    /// ```
+    pub fn format_localized<'a>(&'a self, pattern : &'a str, locale : Locale) -> DelayedFormat<'a, StrftimeItems<'a>> {
+        DelayedFormat::new_localized(self, StrftimeItems::new(pattern), locale)
+    }
+
+    /// Format lazily with directional marks around numbers, for right-to-left texts
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use persian_date::bidi::BidiMark;
+    /// use persian_date::structure::PDate;
+    ///
+    /// let pdate = PDate::from_time_millis(1712136600000);
+    /// assert_eq!(pdate.format_bidi("%Y/%m/%d %H:%M", BidiMark::Isolate).to_string(), "\u{2066}1403/01/15\u{2069} \u{2066}13:00\u{2069}");
+    /// ```
+    pub fn format_bidi<'a>(&'a self, pattern : &'a str, mark : BidiMark) -> BidiFormat<DelayedFormat<'a, StrftimeItems<'a>>> {
+        BidiFormat::new(self.format_lazy(pattern), mark)
+    }
+
+    /// Format lazily with precompiled pattern
+    pub fn format_with_items<'a, 'b>(&'a self, items : &'a FormatItems<'b>) -> DelayedFormat<'a, impl Iterator<Item = Item<'b>> + Clone + 'a> {
+        DelayedFormat::new(self, items.iter())
+    }
+
+    pub(crate) fn write_directive(&self, f : &mut Formatter<'_>, directive : Directive, modifier : Modifier, locale : Option<Locale>) -> core::fmt::Result {
+        let field = match directive {
+            Directive::Year => Field::Number(self.year as i64, 4, Pad::None),
+            Directive::Century => Field::Number(self.year.div_euclid(100) as i64, 2, Pad::Zero),
+            Directive::YearMod100 => Field::Number(self.year.rem_euclid(100) as i64, 2, Pad::Zero),
+            Directive::Month => Field::Number(self.month as i64, 2, Pad::Zero),
+            Directive::MonthName => Field::Text(locale.unwrap_or_default().month_name(self.month)),
+            Directive::Day => Field::Number(self.day as i64, 2, Pad::Zero),
+            Directive::DaySpacePadded => Field::Number(self.day as i64, 2, Pad::Space),
+            Directive::DayName => Field::Text(locale.unwrap_or_default().day_name(self.day_of_week())),
+            Directive::DayOfWeek => Field::Number(self.day_of_week() as i64, 1, Pad::None),
+            Directive::WeekOfYear => Field::Number((self.day_of_year() / 7) as i64, 2, Pad::Zero),
+            Directive::DayOfYear => Field::Number(self.day_of_year() as i64, 3, Pad::Zero),
+            Directive::Hour => Field::Number(self.hour as i64, 2, Pad::Zero),
+            Directive::HourSpacePadded => Field::Number(self.hour as i64, 2, Pad::Space),
+            Directive::Hour12 => Field::Number(self.hour_12() as i64, 2, Pad::Zero),
+            Directive::Hour12SpacePadded => Field::Number(self.hour_12() as i64, 2, Pad::Space),
+            Directive::TimeOfDay => Field::Text(if self.is_mid_night() { "قبل از ظهر" } else { "بعد از ظهر" }),
+            Directive::ShortTimeOfDay => Field::Text(if self.is_mid_night() { "ق.ظ" } else { "ب.ظ" }),
+            Directive::Minute => Field::Number(self.minute as i64, 2, Pad::Zero),
+            Directive::Second => Field::Number(self.second as i64, 2, Pad::Zero),
+            Directive::Nanosecond => Field::Number((self.nano_second % 1_000_000_000) as i64, 9, Pad::Zero),
+            Directive::SecondFraction => {
+                let nanos = self.nano_second % 1_000_000_000;
+                return if nanos == 0 {
+                    Ok(())
+                } else if nanos % 1_000_000 == 0 {
+                    write!(f,".{:03}",nanos / 1_000_000)
+                } else if nanos % 1_000 == 0 {
+                    write!(f,".{:06}",nanos / 1_000)
+                } else {
+                    write!(f,".{:09}",nanos)
+                }
+            }
+            Directive::TimeZone => {
+                let offset = self.get_zoned_date().offset().fix().local_minus_utc();
+                let sign = if offset < 0 { '-' } else { '+' };
+                let offset = offset.abs();
+                return write!(f,"{}{:02}:{:02}",sign,offset / 3600,offset / 60 % 60)
+            }
+            Directive::GrgYear => Field::Number(self.grg_year as i64, 4, Pad::None),
+            Directive::GrgYearMod100 => Field::Number(self.grg_year.rem_euclid(100) as i64, 2, Pad::Zero),
+            Directive::GrgMonth => Field::Number(self.grg_month as i64, 2, Pad::Zero),
+            Directive::GrgMonthName => Field::Text(locale.unwrap_or(Locale::English).grg_month_name(self.grg_month)),
+            Directive::GrgDay => Field::Number(self.grg_day as i64, 2, Pad::Zero),
+            Directive::GrgDaySpacePadded => Field::Number(self.grg_day as i64, 2, Pad::Space),
+            Directive::GrgDayName => Field::Text(locale.unwrap_or(Locale::English).day_name(self.day_of_week())),
+            Directive::HijriYear => Field::Number(self.hijri_year() as i64, 4, Pad::None),
+            Directive::HijriMonth => Field::Number(self.hijri_month() as i64, 2, Pad::Zero),
+            Directive::HijriMonthName => Field::Text(crate::locale::HIJRI_MONTH_NAMES[(self.hijri_month() - 1) as usize]),
+            Directive::HijriDay => Field::Number(self.hijri_day() as i64, 2, Pad::Zero),
+            Directive::HijriDaySpacePadded => Field::Number(self.hijri_day() as i64, 2, Pad::Space),
+            Directive::ShortDayName => match locale {
+                Some(Locale::English) => Field::Text(&Locale::English.day_name(self.day_of_week())[..3]),
+                _ => Field::Text(crate::locale::PERSIAN_SHORT_DAY_NAMES[self.day_of_week() as usize]),
+            },
+            Directive::Millisecond => Field::Number(((self.nano_second % 1_000_000_000) / 1_000_000) as i64, 3, Pad::Zero),
+            Directive::TimeZoneCompact => {
+                let offset = self.get_zoned_date().offset().fix().local_minus_utc();
+                let sign = if offset < 0 { '-' } else { '+' };
+                let offset = offset.abs();
+                return write!(f,"{}{:02}{:02}",sign,offset / 3600,offset / 60 % 60)
+            }
+            Directive::Timestamp => Field::Number(self.time_millis.div_euclid(1000), 1, Pad::None),
+            Directive::TimestampMillis => Field::Number(self.time_millis, 1, Pad::None),
+            Directive::GrgShortMonthName => match locale.unwrap_or(Locale::English) {
+                Locale::English => Field::Text(&Locale::English.grg_month_name(self.grg_month)[..3]),
+                locale => Field::Text(locale.grg_month_name(self.grg_month)),
+            },
+            Directive::GrgDayOfWeek => Field::Number(((self.day_of_week() + 6) % 7) as i64, 1, Pad::None),
+            Directive::GrgDayOfYear => Field::Number(self.get_zoned_date().ordinal() as i64, 3, Pad::Zero),
+            Directive::EraYear(era) => Field::Number(self.era_year(era) as i64, 4, Pad::None),
+            Directive::FasliMonth => Field::Number(self.fasli_month() as i64, 2, Pad::Zero),
+            Directive::FasliMonthName => Field::Text(era::fasli_month_name(self.fasli_month())),
+            Directive::FasliDay => Field::Number(self.fasli_day() as i64, 2, Pad::Zero),
+            Directive::FasliDayName => Field::Text(self.fasli_day_name()),
+            Directive::YearInWords => return words::write_cardinal(f, self.year as i64),
+            Directive::DayOrdinal => return words::write_ordinal(f, self.day as i64),
+            Directive::MonthNameWithMah => return write!(f,"{} ماه",locale.unwrap_or_default().month_name(self.month)),
+            Directive::ShortMonthName => match locale.unwrap_or_default() {
+                Locale::English => Field::Text(&Locale::English.month_name(self.month)[..3]),
+                locale => Field::Text(locale.month_name(self.month)),
+            },
+            Directive::MinDayName => match locale {
+                Some(Locale::English) => Field::Text(&Locale::English.day_name(self.day_of_week())[..2]),
+                _ => Field::Text(crate::locale::PERSIAN_SHORT_DAY_NAMES[self.day_of_week() as usize]),
+            },
+            Directive::GrgDayOrdinal => return words::write_ordinal(f, self.grg_day as i64),
+        };
+        field.write(f, modifier)
+    }
+}
+
+/// Value of a directive before applying modifiers
+#[cfg(feature = "tz")]
+enum Field {
+    /// Value, width and default padding
+    Number(i64, usize, Pad),
+    Text(&'static str),
+}
+
+#[cfg(feature = "tz")]
+impl Field {
+    fn write(&self, f : &mut Formatter<'_>, modifier : Modifier) -> core::fmt::Result {
+        match *self {
+            Field::Number(value, width, default_pad) => {
+                let pad = if modifier.pad == Pad::Default { default_pad } else { modifier.pad };
+                match pad {
+                    Pad::Zero => write!(f,"{:0width$}",value),
+                    Pad::Space => write!(f,"{:width$}",value),
+                    _ => write!(f,"{}",value),
+                }
+            }
+            Field::Text(text) if modifier.uppercase => {
+                text.chars().flat_map(char::to_uppercase).try_for_each(|c| f.write_char(c))
+            }
+            Field::Text(text) => f.write_str(text),
+        }
+    }
+}
+
+#[cfg(feature = "tz")]
 impl Format for PDate {
     fn format(&self, pattern: &str) -> String {
-        pattern
-            .replace("%Y",&self.year().to_string())
-            .replace("%C",&self.year().to_string()[..2])
-            .replace("%y",&self.year().to_string()[2..])
-            .replace("%m",&format!("{:02}",self.month()))
-            .replace("%B",&self.month_name())
-            .replace("%d",&format!("{:02}",self.day()))
-            .replace("%e",&format!("{:2}",self.day()))
-            .replace("%A",&self.day_name())
-            .replace("%w",&self.day_of_week().to_string())
-            .replace("%U",&format!("{:02}",self.day_of_year() / 7))
-            .replace("%j",&format!("{:03}",self.day_of_year()))
-            .replace("%H",&format!("{:02}",self.hour()))
-            .replace("%k",&format!("{:2}",self.hour()))
-            .replace("%I",&format!("{:02}",self.hour_12()))
-            .replace("%l",&format!("{:2}",self.hour_12()))
-            .replace("%P",&self.time_of_day())
-            .replace("%p",&self.short_time_of_day())
-            .replace("%M",&format!("{:02}",self.minute()))
-            .replace("%S",&format!("{:02}",self.second()))
-            .replace("%f",&self.nano_second.to_string())
-            .replace("%.f",&format!("{}.{}",self.second,self.nano_second))
-            .replace("%:z",&self.format_timezone())
+        self.format_lazy(pattern).to_string()
     }
 }
 
+#[cfg(feature = "tz")]
 impl Display for PDate {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f,"{}",self.format("%Y-%m-%d %H:%M:%S %:z"))
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f,"{}",self.format_lazy("%Y-%m-%d %H:%M:%S %:z"))
     }
 }
 
+#[cfg(feature = "tz")]
 impl Debug for PDate {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f,"{}",self.format("%Y-%m-%d %H:%M:%S %:z"))
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f,"{}",self.format_lazy("%Y-%m-%d %H:%M:%S %:z"))
+    }
+}
+
+impl Display for NaiveJalaliDate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f,"{}-{:02}-{:02}",self.year,self.month,self.day)
+    }
+}
+
+impl Debug for NaiveJalaliDate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f,"{}-{:02}-{:02}",self.year,self.month,self.day)
+    }
+}
+
+/// Predefined date format of a locale (like CLDR date styles)
+///
+/// | Style | Persian | Dari |
+/// |-------|---------|------|
+/// | `Full` | `چهارشنبه ۱۵ فروردین ۱۴۰۳ ه‍.ش.` | `چهارشنبه ۱۵ حمل ۱۴۰۳ ه‍.ش.` |
+/// | `Long` | `۱۵ فروردین ۱۴۰۳ ه‍.ش.` | `۱۵ حمل ۱۴۰۳ ه‍.ش.` |
+/// | `Medium` | `۱۵ فروردین ۱۴۰۳` | `۱۵ حمل ۱۴۰۳` |
+/// | `Short` | `۱۴۰۳/۱/۱۵` | `۱۴۰۳/۱/۱۵` |
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DateStyle {
+    Full,
+    Long,
+    Medium,
+    Short,
+}
+
+/// Predefined time format of a locale (like CLDR time styles)
+///
+/// | Style | Example |
+/// |-------|---------|
+/// | `Full` | `۹:۳۰:۰۰ (Iran)` |
+/// | `Long` | `۹:۳۰:۰۰ (+۰۳:۳۰)` |
+/// | `Medium` | `۹:۳۰:۰۰` |
+/// | `Short` | `۹:۳۰` |
+///
+/// `Full` writes the IANA name of timezone (like `Iran` or `Asia/Kabul`) as is, zone names are not localized
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TimeStyle {
+    Full,
+    Long,
+    Medium,
+    Short,
+}
+
+impl DateStyle {
+    fn write(&self, f : &mut impl Write, date : &NaiveJalaliDate, locale : Locale) -> core::fmt::Result {
+        let month = locale.month_name(date.month);
+        match self {
+            DateStyle::Full => write!(f,"{} {} {} {} {}",locale.day_name(date.day_of_week()),date.day,month,date.year,locale.era()),
+            DateStyle::Long => write!(f,"{} {} {} {}",date.day,month,date.year,locale.era()),
+            DateStyle::Medium => write!(f,"{} {} {}",date.day,month,date.year),
+            DateStyle::Short => write!(f,"{}/{}/{}",date.year,date.month,date.day),
+        }
+    }
+}
+
+#[cfg(feature = "tz")]
+impl TimeStyle {
+    fn write(&self, f : &mut impl Write, date : &PDate) -> core::fmt::Result {
+        write!(f,"{}:{:02}",date.hour,date.minute)?;
+        match self {
+            TimeStyle::Full => write!(f,":{:02} ({})",date.second,date.time_zone.name()),
+            TimeStyle::Long => write!(f,":{:02} ({})",date.second,date.format_lazy("%:z")),
+            TimeStyle::Medium => write!(f,":{:02}",date.second),
+            TimeStyle::Short => Ok(()),
+        }
+    }
+}
+
+/// Date and/or time written in a predefined locale style, see [DateStyle] and [TimeStyle]
+#[derive(Clone)]
+pub struct StyledFormat<'a> {
+    date : NaiveJalaliDate,
+    date_style : Option<DateStyle>,
+    #[cfg(feature = "tz")]
+    time : Option<(&'a PDate, TimeStyle)>,
+    #[cfg(not(feature = "tz"))]
+    time : core::marker::PhantomData<&'a ()>,
+    locale : Locale,
+}
+
+impl Display for StyledFormat<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let mut f = DigitsWriter { inner: f, digits: self.locale.digits() };
+        if let Some(style) = self.date_style {
+            style.write(&mut f, &self.date, self.locale)?;
+        }
+        #[cfg(feature = "tz")]
+        if let Some((date, style)) = self.time {
+            match self.date_style {
+                Some(DateStyle::Full | DateStyle::Long) => f.write_str(self.locale.time_separator(true))?,
+                Some(_) => f.write_str(self.locale.time_separator(false))?,
+                None => {}
+            }
+            style.write(&mut f, date)?;
+        }
+        Ok(())
+    }
+}
+
+impl NaiveJalaliDate {
+    /// Format date in a predefined style of locale
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use persian_date::format::DateStyle;
+    /// use persian_date::locale::Locale;
+    /// use persian_date::structure::NaiveJalaliDate;
+    ///
+    /// let date = NaiveJalaliDate::from_ymd_opt(1403, 1, 15).unwrap();
+    /// assert_eq!(date.format_style(DateStyle::Full, Locale::Persian).to_string(), "چهارشنبه ۱۵ فروردین ۱۴۰۳ ه\u{200d}.ش.");
+    /// assert_eq!(date.format_style(DateStyle::Medium, Locale::Dari).to_string(), "۱۵ حمل ۱۴۰۳");
+    /// assert_eq!(date.format_style(DateStyle::Short, Locale::Persian).to_string(), "۱۴۰۳/۱/۱۵");
+    /// ```
+    pub fn format_style(&self, style : DateStyle, locale : Locale) -> StyledFormat<'static> {
+        StyledFormat { date: *self, date_style: Some(style), time: Default::default(), locale }
+    }
+}
+
+#[cfg(feature = "tz")]
+impl PDate {
+    /// Format date in a predefined style of locale
+    pub fn format_date_style(&self, style : DateStyle, locale : Locale) -> StyledFormat<'_> {
+        StyledFormat { date: self.jalali_date(), date_style: Some(style), time: None, locale }
+    }
+
+    /// Format time in a predefined style of locale
+    pub fn format_time_style(&self, style : TimeStyle, locale : Locale) -> StyledFormat<'_> {
+        StyledFormat { date: self.jalali_date(), date_style: None, time: Some((self, style)), locale }
+    }
+
+    /// Format date and time in predefined styles of locale
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use persian_date::format::{DateStyle, TimeStyle};
+    /// use persian_date::locale::Locale;
+    /// use persian_date::structure::PDate;
+    ///
+    /// let pdate = PDate::from_time_millis(1712136600000);
+    /// assert_eq!(pdate.format_datetime_style(DateStyle::Full, TimeStyle::Short, Locale::Persian).to_string(), "چهارشنبه ۱۵ فروردین ۱۴۰۳ ه\u{200d}.ش.، ساعت ۱۳:۰۰");
+    /// assert_eq!(pdate.format_datetime_style(DateStyle::Short, TimeStyle::Long, Locale::Dari).to_string(), "۱۴۰۳/۱/۱۵، ۱۳:۰۰:۰۰ (+۰۳:۳۰)");
+    /// assert_eq!(pdate.format_time_style(TimeStyle::Full, Locale::Persian).to_string(), "۱۳:۰۰:۰۰ (Iran)");
+    /// ```
+    pub fn format_datetime_style(&self, date_style : DateStyle, time_style : TimeStyle, locale : Locale) -> StyledFormat<'_> {
+        StyledFormat { date: self.jalali_date(), date_style: Some(date_style), time: Some((self, time_style)), locale }
     }
 }
@@ -0,0 +1,145 @@
+//! Month grids for date pickers and calendar views
+//!
+//! A [MonthGrid] has 5 or 6 rows of 7 cells, starting from the configured first day of week.
+//! Leading and trailing cells are filled with days of the adjacent months
+//!
+//! # Example
+//!
+//! ```
+//! use persian_date::grid;
+//! use persian_date::jdate;
+//!
+//! let grid = grid::month_grid(1403, 1, 0).unwrap().with_today(jdate!(1403-01-15));
+//! assert_eq!(grid.rows().len(), 5);
+//! assert_eq!(grid.weekdays(), [0, 1, 2, 3, 4, 5, 6]);
+//!
+//! // farvardin 1403 starts on wednesday, so the first row has 4 days of esfand 1402
+//! let first = grid.rows()[0][0];
+//! assert_eq!(first.date, jdate!(1402-12-26));
+//! assert!(!first.in_month);
+//!
+//! let cell = grid.rows()[2][4];
+//! assert_eq!(cell.date, jdate!(1403-01-15));
+//! assert!(cell.is_today);
+//! assert_eq!(cell.gregorian, (2024, 4, 3));
+//! assert_eq!(cell.hijri, (1445, 9, 24));
+//!
+//! assert!(grid.rows()[1][6].is_weekend);
+//! assert_eq!(grid.rows()[2][2].holiday, Some("روز طبیعت"));
+//! ```
+
+use crate::calendar;
+use crate::profile::CalendarProfile;
+use crate::structure::NaiveJalaliDate;
+
+/// A day in a [MonthGrid]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DayCell {
+    pub date : NaiveJalaliDate,
+    /// Day belongs to the month of grid, not to an adjacent month
+    pub in_month : bool,
+    pub is_today : bool,
+    pub is_weekend : bool,
+    /// Name of official holiday, weekends are not included
+    pub holiday : Option<&'static str>,
+    /// Gregorian `(year, month, day)`
+    pub gregorian : (i32, u32, u32),
+    /// Hijri (lunar) `(year, month, day)`
+    pub hijri : (i32, u32, u32),
+}
+
+/// Weeks of a jalali month, see [module](self)
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MonthGrid {
+    year : i32,
+    month : u32,
+    week_start : u32,
+    cells : [[DayCell; 7]; 6],
+    row_count : usize,
+}
+
+impl MonthGrid {
+    /// Return the jalali year
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Return the jalali month
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Return first day of week (saturday is 0)
+    pub fn week_start(&self) -> u32 {
+        self.week_start
+    }
+
+    /// Return day of week of each column (saturday is 0)
+    pub fn weekdays(&self) -> [u32; 7] {
+        core::array::from_fn(|column| (self.week_start + column as u32) % 7)
+    }
+
+    /// Return rows of grid, 5 or 6 weeks
+    pub fn rows(&self) -> &[[DayCell; 7]] {
+        &self.cells[..self.row_count]
+    }
+
+    /// Return all cells of grid row by row
+    pub fn cells(&self) -> impl Iterator<Item = &DayCell> {
+        self.rows().iter().flatten()
+    }
+
+    /// Mark today, flags of other days are cleared
+    pub fn with_today(mut self, today : NaiveJalaliDate) -> Self {
+        self.cells.iter_mut().flatten().for_each(|cell| cell.is_today = cell.date == today);
+        self
+    }
+}
+
+/// Make grid of jalali month with weekend and holidays of the iranian profile
+///
+/// Return `None` on invalid month, or when its weeks are out of supported range. `week_start` is the day of first column (saturday is 0).
+/// No day is marked as today, use [MonthGrid::with_today]
+pub fn month_grid(year : i32, month : u32, week_start : u32) -> Option<MonthGrid> {
+    CalendarProfile::Iranian.month_grid(year, month, week_start)
+}
+
+impl CalendarProfile {
+    /// Make grid of jalali month with weekend and holidays of profile, see [month_grid]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use persian_date::profile::CalendarProfile;
+    ///
+    /// let grid = CalendarProfile::Afghan.month_grid(1403, 12, 6).unwrap();
+    /// assert_eq!(grid.rows().len(), 5);
+    /// assert_eq!(grid.weekdays()[0], 6);
+    /// assert!(grid.rows()[0][0].is_weekend);
+    /// assert!(grid.rows().iter().flatten().all(|cell| !cell.is_today));
+    /// ```
+    pub fn month_grid(&self, year : i32, month : u32, week_start : u32) -> Option<MonthGrid> {
+        let first = NaiveJalaliDate::from_ymd_opt(year, month, 1)?;
+        let week_start = week_start % 7;
+        let offset = (first.day_of_week() + 7 - week_start) % 7;
+        let start = first.to_jdn() - offset as i64;
+        // all six weeks are filled, with days of adjacent months
+        NaiveJalaliDate::from_jdn_opt(start)?;
+        NaiveJalaliDate::from_jdn_opt(start + 41)?;
+        let row_count = (offset + calendar::month_days(year, month)).div_ceil(7) as usize;
+        let cells = core::array::from_fn(|row| core::array::from_fn(|column| {
+            let jdn = start + (row * 7 + column) as i64;
+            let date = NaiveJalaliDate::from_jdn(jdn);
+            DayCell {
+                date,
+                in_month: date.month == month,
+                is_today: false,
+                is_weekend: self.is_weekend(&date),
+                holiday: self.holiday(&date),
+                gregorian: calendar::jdn_to_gregorian(jdn),
+                hijri: calendar::jdn_to_hijri(jdn),
+            }
+        }));
+        Some(MonthGrid { year, month, week_start, cells, row_count })
+    }
+}
@@ -0,0 +1,188 @@
+//! Human readable relative time, like `۵ دقیقه پیش` or `فردا ساعت ۱۰`
+//!
+//! Short distances are measured by [time_millis](crate::structure::Reader::time_millis),
+//! longer ones by jalali days, weeks, months and years in time zone of the reference date
+//!
+//! # Example
+//!
+//! ```
+//! use persian_date::humanize::{HumanizeOptions, Thresholds};
+//! use persian_date::locale::Locale;
+//! use persian_date::jdatetime;
+//!
+//! let reference = jdatetime!(1403-01-15 09:30:00);
+//! assert_eq!(jdatetime!(1403-01-15 09:29:50).humanize(&reference), "همین الان");
+//! assert_eq!(jdatetime!(1403-01-15 09:25:00).humanize(&reference), "۵ دقیقه پیش");
+//! assert_eq!(jdatetime!(1403-01-16 10:00:00).humanize(&reference), "فردا ساعت ۱۰");
+//! assert_eq!(jdatetime!(1403-01-14 08:15:00).humanize(&reference), "دیروز ساعت ۸:۱۵");
+//! assert_eq!(jdatetime!(1403-01-24 12:00:00).humanize(&reference), "هفته آینده");
+//! assert_eq!(jdatetime!(1402-11-10 12:00:00).humanize(&reference), "۲ ماه پیش");
+//!
+//! let options = HumanizeOptions { locale: Locale::English, ..Default::default() };
+//! assert_eq!(jdatetime!(1403-01-15 07:30:00).humanize_with(&reference, &options), "2 hours ago");
+//! assert_eq!(jdatetime!(1403-01-16 10:00:00).humanize_with(&reference, &options), "tomorrow at 10:00");
+//!
+//! let options = HumanizeOptions { thresholds: Thresholds { hours: 1, ..Default::default() }, ..Default::default() };
+//! assert_eq!(jdatetime!(1403-01-15 07:30:00).humanize_with(&reference, &options), "امروز ساعت ۷:۳۰");
+//! ```
+
+use core::fmt::Write;
+use alloc::string::String;
+use crate::calendar;
+use crate::locale::{Digits, DigitsWriter, Locale};
+use crate::structure::{PDate, Setter};
+
+/// Limits of each unit, a distance below the limit is written in that unit
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Thresholds {
+    /// Seconds written as "just now", default 45
+    pub now : i64,
+    /// Minutes written as minutes, default 45
+    pub minutes : i64,
+    /// Hours written as hours, default 6, longer distances use calendar days
+    pub hours : i64,
+    /// Days written as days, default 7
+    pub days : i64,
+    /// Weeks written as weeks, default 4
+    pub weeks : i64,
+    /// Months written as months, default 12
+    pub months : i64,
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        Thresholds { now: 45, minutes: 45, hours: 6, days: 7, weeks: 4, months: 12 }
+    }
+}
+
+/// Options of [PDate::humanize_with]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct HumanizeOptions {
+    /// Language of output, [Locale::English] writes english and other locales persian
+    pub locale : Locale,
+    /// Digits of numbers, default is digits of locale
+    pub digits : Option<Digits>,
+    /// Limits of units
+    pub thresholds : Thresholds,
+}
+
+impl Default for HumanizeOptions {
+    fn default() -> Self {
+        HumanizeOptions { locale: Locale::Persian, digits: None, thresholds: Thresholds::default() }
+    }
+}
+
+/// Relative distance in one unit
+enum Distance {
+    Now,
+    Minutes(i64),
+    Hours(i64),
+    /// Days with time of day, for today, yesterday and tomorrow
+    DayAt(i64, u32, u32),
+    Days(i64),
+    Weeks(i64),
+    Months(i64),
+    Years(i64),
+}
+
+impl PDate {
+    /// Return distance from reference date in persian words, like `۳ روز پیش`
+    pub fn humanize(&self, reference : &PDate) -> String {
+        self.humanize_with(reference, &HumanizeOptions::default())
+    }
+
+    /// Return distance from reference date with options
+    pub fn humanize_with(&self, reference : &PDate, options : &HumanizeOptions) -> String {
+        let distance = self.distance(reference, &options.thresholds);
+        let mut out = String::new();
+        let mut writer = DigitsWriter { inner: &mut out, digits: options.digits.unwrap_or(options.locale.digits()) };
+        let _ = match options.locale {
+            Locale::English => write_english(&mut writer, distance),
+            _ => write_persian(&mut writer, distance),
+        };
+        out
+    }
+
+    fn distance(&self, reference : &PDate, thresholds : &Thresholds) -> Distance {
+        let seconds = (self.time_millis - reference.time_millis) / 1000;
+        if seconds.abs() < thresholds.now {
+            return Distance::Now;
+        }
+        let minutes = (seconds as f64 / 60.0).round() as i64;
+        if minutes.abs() < thresholds.minutes {
+            return Distance::Minutes(if minutes == 0 { seconds.signum() } else { minutes });
+        }
+        let hours = (seconds as f64 / 3600.0).round() as i64;
+        if hours.abs() < thresholds.hours {
+            return Distance::Hours(if hours == 0 { seconds.signum() } else { hours });
+        }
+        let mut date = PDate::from_time_millis(self.time_millis);
+        date.set_time_zone(reference.time_zone);
+        let days = calendar::jalali_to_jdn(date.year, date.month, date.day) - calendar::jalali_to_jdn(reference.year, reference.month, reference.day);
+        if days.abs() <= 1 {
+            return Distance::DayAt(days, date.hour, date.minute);
+        }
+        if days.abs() < thresholds.days {
+            return Distance::Days(days);
+        }
+        let weeks = days / 7;
+        if weeks.abs() < thresholds.weeks {
+            return Distance::Weeks(weeks);
+        }
+        let months = (date.year as i64 * 12 + date.month as i64) - (reference.year as i64 * 12 + reference.month as i64);
+        if months.abs() < thresholds.months {
+            return Distance::Months(if months == 0 { days.signum() } else { months });
+        }
+        Distance::Years((date.year - reference.year) as i64)
+    }
+}
+
+fn write_persian(f : &mut impl Write, distance : Distance) -> core::fmt::Result {
+    let (count, unit) = match distance {
+        Distance::Now => return f.write_str("همین الان"),
+        Distance::DayAt(days, hour, minute) => {
+            f.write_str(match days { -1 => "دیروز", 0 => "امروز", _ => "فردا" })?;
+            return if minute == 0 { write!(f," ساعت {}",hour) } else { write!(f," ساعت {}:{:02}",hour,minute) };
+        }
+        Distance::Weeks(-1) => return f.write_str("هفته گذشته"),
+        Distance::Weeks(1) => return f.write_str("هفته آینده"),
+        Distance::Months(-1) => return f.write_str("ماه گذشته"),
+        Distance::Months(1) => return f.write_str("ماه آینده"),
+        Distance::Years(-1) => return f.write_str("پارسال"),
+        Distance::Years(1) => return f.write_str("سال آینده"),
+        Distance::Minutes(count) => (count, "دقیقه"),
+        Distance::Hours(count) => (count, "ساعت"),
+        Distance::Days(count) => (count, "روز"),
+        Distance::Weeks(count) => (count, "هفته"),
+        Distance::Months(count) => (count, "ماه"),
+        Distance::Years(count) => (count, "سال"),
+    };
+    write!(f,"{} {} {}",count.abs(),unit,if count < 0 { "پیش" } else { "دیگر" })
+}
+
+fn write_english(f : &mut impl Write, distance : Distance) -> core::fmt::Result {
+    let (count, unit) = match distance {
+        Distance::Now => return f.write_str("just now"),
+        Distance::DayAt(days, hour, minute) => {
+            return write!(f,"{} at {}:{:02}",match days { -1 => "yesterday", 0 => "today", _ => "tomorrow" },hour,minute);
+        }
+        Distance::Weeks(-1) => return f.write_str("last week"),
+        Distance::Weeks(1) => return f.write_str("next week"),
+        Distance::Months(-1) => return f.write_str("last month"),
+        Distance::Months(1) => return f.write_str("next month"),
+        Distance::Years(-1) => return f.write_str("last year"),
+        Distance::Years(1) => return f.write_str("next year"),
+        Distance::Minutes(count) => (count, "minute"),
+        Distance::Hours(count) => (count, "hour"),
+        Distance::Days(count) => (count, "day"),
+        Distance::Weeks(count) => (count, "week"),
+        Distance::Months(count) => (count, "month"),
+        Distance::Years(count) => (count, "year"),
+    };
+    let plural = if count.abs() == 1 { "" } else { "s" };
+    if count < 0 {
+        write!(f,"{} {}{} ago",-count,unit,plural)
+    } else {
+        write!(f,"in {} {}{}",count,unit,plural)
+    }
+}
@@ -0,0 +1,149 @@
+//! Conversions between persian date types and other date-time libraries
+//!
+//! Enable `time` feature for [time](https://docs.rs/time) crate
+//! and `jiff` feature for [jiff](https://docs.rs/jiff) crate
+//!
+//! # Example
+//!
+//! ```
+//! # #[cfg(all(feature = "tz", feature = "time"))] {
+//! use persian_date::structure::{NaiveJalaliDate, PDate};
+//!
+//! let date = time::Date::from_calendar_date(2024, time::Month::April, 3).unwrap();
+//! let jalali = NaiveJalaliDate::try_from(date).unwrap();
+//! assert_eq!(jalali.to_string(), "1403-01-15");
+//!
+//! let pdate = PDate::from_time_millis(1712136600000);
+//! let odt = time::OffsetDateTime::try_from(&pdate).unwrap();
+//! assert_eq!(PDate::from(odt), pdate);
+//! # }
+//! ```
+
+use core::fmt::{Display, Formatter};
+use chrono::Datelike;
+#[cfg(feature = "tz")]
+use chrono::Offset;
+#[cfg(all(feature = "tz", feature = "jiff"))]
+use chrono_tz::Tz;
+#[cfg(feature = "time")]
+use crate::calendar;
+use crate::structure::NaiveJalaliDate;
+#[cfg(feature = "tz")]
+use crate::structure::PDate;
+#[cfg(all(feature = "tz", feature = "jiff"))]
+use crate::structure::Setter;
+
+/// Error of conversions from dates out of supported jalali range
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct OutOfRange;
+
+impl Display for OutOfRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("date is out of supported jalali range")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OutOfRange {}
+
+#[cfg(feature = "tz")]
+impl PDate {
+    fn offset_seconds(&self) -> i32 {
+        self.get_zoned_date().offset().fix().local_minus_utc()
+    }
+
+    fn time_nanos(&self) -> i128 {
+        self.time_millis as i128 * 1_000_000 + (self.nano_second % 1_000_000) as i128
+    }
+}
+
+#[cfg(all(feature = "tz", feature = "time"))]
+impl TryFrom<&PDate> for time::OffsetDateTime {
+    type Error = time::error::ComponentRange;
+
+    fn try_from(pdate: &PDate) -> Result<Self, Self::Error> {
+        let offset = time::UtcOffset::from_whole_seconds(pdate.offset_seconds())?;
+        let date = time::OffsetDateTime::from_unix_timestamp_nanos(pdate.time_nanos())?;
+        Ok(date.to_offset(offset))
+    }
+}
+
+/// Keep the instant, truncated to milliseconds
+///
+/// The offset of source is lost (`time` has no zone names), timezone of the result
+/// is default timezone (Iran), use [set_time_zone](crate::structure::Setter::set_time_zone) to change it
+#[cfg(all(feature = "tz", feature = "time"))]
+impl From<time::OffsetDateTime> for PDate {
+    fn from(date: time::OffsetDateTime) -> Self {
+        PDate::from_time_millis((date.unix_timestamp_nanos() / 1_000_000) as i64)
+    }
+}
+
+/// Fails on dates out of [MIN_GRG_YEAR](calendar::MIN_GRG_YEAR)..=[MAX_GRG_YEAR](calendar::MAX_GRG_YEAR),
+/// which `time` allows with its `large-dates` feature
+#[cfg(feature = "time")]
+impl TryFrom<time::Date> for NaiveJalaliDate {
+    type Error = OutOfRange;
+
+    fn try_from(date: time::Date) -> Result<Self, Self::Error> {
+        let (year, month, day) = calendar::checked_gregorian_to_jalali(date.year(), date.month() as u32, date.day() as u32).ok_or(OutOfRange)?;
+        Ok(NaiveJalaliDate { year, month, day })
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<NaiveJalaliDate> for time::Date {
+    type Error = time::error::ComponentRange;
+
+    fn try_from(date: NaiveJalaliDate) -> Result<Self, Self::Error> {
+        let grg = date.to_gregorian();
+        let month = time::Month::try_from(grg.month() as u8)?;
+        time::Date::from_calendar_date(grg.year(), month, grg.day() as u8)
+    }
+}
+
+/// Timezone of the result is the same IANA zone when available, otherwise fixed offset of date
+#[cfg(all(feature = "tz", feature = "jiff"))]
+impl TryFrom<&PDate> for jiff::Zoned {
+    type Error = jiff::Error;
+
+    fn try_from(pdate: &PDate) -> Result<Self, Self::Error> {
+        let time_zone = jiff::tz::TimeZone::get(pdate.time_zone.name()).or_else(|_| {
+            jiff::tz::Offset::from_seconds(pdate.offset_seconds()).map(jiff::tz::TimeZone::fixed)
+        })?;
+        Ok(jiff::Timestamp::from_nanosecond(pdate.time_nanos())?.to_zoned(time_zone))
+    }
+}
+
+/// Keep the instant (truncated to milliseconds) and timezone,
+/// fixed offsets and unknown timezones fall back to default timezone (Iran)
+#[cfg(all(feature = "tz", feature = "jiff"))]
+impl From<jiff::Zoned> for PDate {
+    fn from(date: jiff::Zoned) -> Self {
+        let mut pdate = PDate::from_time_millis(date.timestamp().as_millisecond());
+        if let Some(time_zone) = date.time_zone().iana_name().and_then(|name| name.parse::<Tz>().ok()) {
+            pdate.set_time_zone(time_zone);
+        }
+        pdate
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl From<jiff::civil::Date> for NaiveJalaliDate {
+    fn from(date: jiff::civil::Date) -> Self {
+        let date = chrono::NaiveDate::from_ymd_opt(date.year() as i32, date.month() as u32, date.day() as u32).unwrap();
+        NaiveJalaliDate::from_gregorian(date)
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl TryFrom<NaiveJalaliDate> for jiff::civil::Date {
+    type Error = jiff::Error;
+
+    fn try_from(date: NaiveJalaliDate) -> Result<Self, Self::Error> {
+        let grg = date.to_gregorian();
+        // years outside of i16 are out of jiff range too, so let jiff report them
+        let year = i16::try_from(grg.year()).unwrap_or(i16::MAX);
+        jiff::civil::Date::new(year, grg.month() as i8, grg.day() as i8)
+    }
+}
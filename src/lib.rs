@@ -1,17 +1,60 @@
-use std::cmp::Ordering;
-use std::ops::Add;
-use chrono::{DateTime, Datelike, Duration, Local, Months, NaiveDate, TimeZone, Timelike, Weekday};
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "alloc")]
+use core::fmt::Write;
+#[cfg(feature = "tz")]
+use core::cmp::Ordering;
+#[cfg(feature = "tz")]
+use core::ops::Add;
+use chrono::{Datelike, NaiveDate, Weekday};
+#[cfg(feature = "tz")]
+use chrono::{DateTime, Duration, Months, NaiveDateTime, TimeZone, Timelike};
+#[cfg(feature = "tz")]
 use chrono_tz::Tz;
-use crate::structure::{PDate, Reader, Setter};
-
+use crate::era::Era;
+use crate::locale::Locale;
+use crate::structure::NaiveJalaliDate;
+#[cfg(feature = "tz")]
+use crate::structure::{PDate, Reader, SetError, Setter};
+
+pub mod bidi;
+pub mod calendar;
+#[cfg(feature = "alloc")]
+pub mod duration;
+pub mod era;
+mod macros;
 pub mod format;
+pub mod grid;
+#[cfg(feature = "tz")]
+pub mod humanize;
+pub mod locale;
+#[cfg(feature = "tz")]
+pub mod moment;
+#[cfg(feature = "tz")]
+pub mod natural;
+#[cfg(feature = "tz")]
+pub mod parse;
+pub mod profile;
+pub mod render;
 pub mod structure;
+#[cfg(feature = "tz")]
+pub mod strptime;
+pub mod words;
+#[cfg(any(feature = "time", feature = "jiff"))]
+pub mod interop;
+
+#[cfg(feature = "tz")]
+#[doc(hidden)]
+pub mod __private {
+    pub use chrono_tz::Tz;
+}
 
-const DAY_NAMES : [&str; 7] = ["شنبه", "یک‌شنبه", "دوشنبه", "سه‌شنبه", "چهارشنبه", "پنج‌شنبه", "جمعه"];
-const EN_DAY_NAMES : [&str; 7] = ["Saturday", "Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday"];
-const MONTH_NAMES : [&str; 12] = ["فروردین", "اردیبهشت", "خرداد", "تیر", "مرداد", "شهریور", "مهر", "آبان", "آذر", "دی", "بهمن", "اسفند"];
-const EN_MONTH_NAMES : [&str; 12] = ["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"];
-
+#[cfg(feature = "tz")]
 impl Default for PDate {
     fn default() -> Self {
         PDate {
@@ -31,18 +74,21 @@ impl Default for PDate {
     }
 }
 
+#[cfg(feature = "tz")]
 impl PartialEq<Self> for PDate {
     fn eq(&self, other: &Self) -> bool {
         self.time_millis() == other.time_millis()
     }
 }
 
+#[cfg(feature = "tz")]
 impl PartialOrd for PDate {
     fn partial_cmp(&self, other: &PDate) -> Option<Ordering> {
         self.time_millis().partial_cmp(&other.time_millis())
     }
 }
 
+#[cfg(feature = "tz")]
 impl PDate {
 
     /** initialize */
@@ -57,14 +103,67 @@ impl PDate {
         pdate
     }
     pub fn from_gregorian_date(year: i32, month: u32, day: u32) -> Self {
-        let date = chrono::Utc::now().with_year(year).unwrap().with_month(month).unwrap().with_day(day).unwrap();
-        Self::from_time_millis(date.timestamp_millis())
+        Self::from_gregorian_date_opt(year, month, day).expect("Invalid gregorian date")
     }
     pub fn from_jalali_date(year: i32, month: u32, day: u32) -> Self {
-        let conv_date = Self::jalali_to_gregorian(year, month as i32, day as i32);
-        let date = Local::now().with_year(conv_date[0]).unwrap()
-            .with_month(conv_date[1] as u32).unwrap().with_day(conv_date[2] as u32).unwrap();
-        Self::from_time_millis(date.timestamp_millis())
+        Self::from_jalali_date_opt(year, month, day).expect("Invalid jalali date")
+    }
+    /// Make date at midnight of gregorian date in default timezone (Iran)
+    ///
+    /// Return `None` when date not exists or is out of supported range
+    pub fn from_gregorian_date_opt(year: i32, month: u32, day: u32) -> Option<Self> {
+        let (year, month, day) = calendar::checked_gregorian_to_jalali(year, month, day)?;
+        Some(Self::from_naive_jalali(NaiveJalaliDate { year, month, day }, 0, 0, 0, Tz::Iran))
+    }
+    /// Make date at midnight of jalali date in default timezone (Iran)
+    ///
+    /// Return `None` when date not exists or is out of supported range
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use persian_date::structure::{PDate, Reader};
+    ///
+    /// let pdate = PDate::from_jalali_date_opt(-1000, 1, 1).unwrap();
+    /// assert_eq!(pdate.year(), -1000);
+    /// assert!(PDate::from_jalali_date_opt(persian_date::calendar::MAX_YEAR + 1, 1, 1).is_none());
+    ///
+    /// let pdate = PDate::from_jalali_date_opt(persian_date::calendar::MAX_YEAR, 12, 29).unwrap();
+    /// assert_eq!((pdate.year(), pdate.month(), pdate.day(), pdate.hour()), (persian_date::calendar::MAX_YEAR, 12, 29, 0));
+    /// ```
+    pub fn from_jalali_date_opt(year: i32, month: u32, day: u32) -> Option<Self> {
+        let date = NaiveJalaliDate::from_ymd_opt(year, month, day)?;
+        Some(Self::from_naive_jalali(date, 0, 0, 0, Tz::Iran))
+    }
+
+    /// Make date from jalali date and time of day in given timezone
+    ///
+    /// Local times skipped by daylight saving are moved forward by one hour
+    pub fn from_naive_jalali(date: NaiveJalaliDate, hour: u32, minute: u32, second: u32, time_zone: Tz) -> Self {
+        let naive = date.to_gregorian().and_hms_opt(hour, minute, second).unwrap();
+        let date = from_local(time_zone, naive).unwrap();
+        let mut pdate = PDate {
+            time_zone,
+            ..PDate::default()
+        };
+        pdate.update_from_date(&date);
+        pdate
+    }
+
+    /// Return jalali date part without time and timezone
+    pub fn jalali_date(&self) -> NaiveJalaliDate {
+        NaiveJalaliDate {
+            year: self.year,
+            month: self.month,
+            day: self.day,
+        }
+    }
+
+    /// Return date in persian words, like `پانزدهم فروردین ماه یک هزار و چهارصد و سه`
+    ///
+    /// Only the date is written, use `%Od %OB %OY` directives of [Format](crate::format::Format) for other layouts
+    pub fn to_words(&self) -> String {
+        self.jalali_date().to_words()
     }
 
     /** main functions */
@@ -77,78 +176,30 @@ impl PDate {
         date.format("%:z").to_string()
     }
 
-    fn gregorian_to_jalali(gy : i32,gm : i32,gd : i32) -> Vec<i32> {
-        let mut out = vec![
-            if gm > 2 { gy + 1 } else { gy },
-            0,
-            0
-        ];
-        {
-            let g_d_m = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
-            out[2] = 355666 + (365 * gy) + ((out[0] + 3) / 4) - ((out[0] + 99) / 100)
-                + ((out[0] + 399) / 400) + gd + g_d_m[gm as usize - 1]
-        }
-        out[0] = -1595 + (33 * (out[2] / 12053));
-        out[2] %= 12053;
-        out[0] += 4 * (out[2] / 1461);
-        out[2] %= 1461;
-        if out[2] > 365 {
-            out[0] += (out[2] - 1) / 365;
-            out[2] = (out[2] - 1) % 365;
-        }
-        if out[2] < 186 {
-            out[1] = 1 + (out[2] / 31);
-            out[2] = 1 + (out[2] % 31);
-        } else {
-            out[1] = 7 + ((out[2] - 186) / 30);
-            out[2] = 1 + ((out[2] - 186) % 30);
-        }
-        out
+    /// Move to a gregorian date, keeping time of day
+    fn set_gregorian_date(&mut self, year: i32, month: u32, day: u32) -> Result<(), SetError> {
+        let time = self.get_zoned_date().time();
+        let naive = NaiveDate::from_ymd_opt(year, month, day).ok_or(SetError::OutOfRange)?.and_time(time);
+        let date = from_local(self.time_zone, naive).ok_or(SetError::OutOfRange)?;
+        self.update_from_date(&date);
+        Ok(())
     }
 
-    fn jalali_to_gregorian(mut jy : i32,jm : i32,jd : i32) -> Vec<i32> {
-        jy += 1595;
-        let mut out = vec![
-            0,
-            0,
-            -355668 + (365 * jy) + ((jy / 33) * 8) + (((jy % 33) + 3) / 4) + jd + if jm < 7 {(jm - 1) * 31} else {((jm - 7) * 30) + 186}
-        ];
-        out[0] = 400 * (out[2] / 146097);
-        out[2] %= 146097;
-        if out[2] > 36524 {
-            out[0] += 100 * (-(-out[2]) / 36524);
-            out[2] %= 36524;
-            if out[2] >= 365 {
-                out[2] += 1;
-            }
-        }
-        out[0] += 4 * (out[2] / 1461);
-        out[2] %= 1461;
-        if out[2] > 365 {
-            out[0] += (out[2] - 1) / 365;
-            out[2] = (out[2] - 1) % 365;
-        }
-        let sal_a = [0, 31, if (out[0] % 4 == 0 && out[0] % 100 != 0) || (out[0] % 400 == 0) {29} else {28},
-            31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
-        while out[1] < 13 && out[2] > sal_a[out[1] as usize] {
-            out[2] -= sal_a[out[1] as usize];
-            out[1] += 1;
-        }
-        out[2] += 1;
-        out
-    }
-
-    fn get_date_from_jalali(&self,jalali : &Vec<i32>) -> DateTime<Tz> {
-        self.time_zone.timestamp_millis_opt(self.time_millis).unwrap()
-            .with_year(jalali[0]).unwrap().with_month(jalali[1] as u32).unwrap().with_day(jalali[2] as u32).unwrap()
+    /// Move to a time of day, keeping the date
+    fn set_time(&mut self, hour: u32, minute: u32, second: u32) -> Result<(), SetError> {
+        let naive = self.get_zoned_date().date_naive()
+            .and_hms_nano_opt(hour, minute, second, self.nano_second).ok_or(SetError::InvalidTime)?;
+        let date = from_local(self.time_zone, naive).ok_or(SetError::OutOfRange)?;
+        self.update_from_date(&date);
+        Ok(())
     }
 
     /** updaters */
     fn update_from_date(&mut self,date_time: &DateTime<Tz>) {
-        let to_jalali = Self::gregorian_to_jalali(date_time.year(), date_time.month() as i32, date_time.day() as i32);
-        self.year = to_jalali[0];
-        self.month = to_jalali[1] as u32;
-        self.day = to_jalali[2] as u32;
+        let (year, month, day) = calendar::gregorian_to_jalali(date_time.year(), date_time.month(), date_time.day());
+        self.year = year;
+        self.month = month;
+        self.day = day;
 
         self.grg_year = date_time.year();
         self.grg_month = date_time.month();
@@ -162,6 +213,220 @@ impl PDate {
     }
 }
 
+/// Resolve local time in timezone, local times skipped by daylight saving are moved forward by one hour
+#[cfg(feature = "tz")]
+fn from_local(time_zone: Tz, naive: NaiveDateTime) -> Option<DateTime<Tz>> {
+    time_zone.from_local_datetime(&naive).earliest()
+        .or_else(|| time_zone.from_local_datetime(&naive.checked_add_signed(Duration::hours(1))?).earliest())
+}
+
+/// Validate jalali date and convert it to gregorian, with the first invalid field as error
+#[cfg(feature = "tz")]
+fn check_jalali(year: i32, month: u32, day: u32) -> Result<(i32, u32, u32), SetError> {
+    if !(calendar::MIN_YEAR..=calendar::MAX_YEAR).contains(&year) {
+        return Err(SetError::InvalidYear);
+    }
+    if !(1..=12).contains(&month) {
+        return Err(SetError::InvalidMonth);
+    }
+    calendar::checked_jalali_to_gregorian(year, month, day).ok_or(SetError::InvalidDay)
+}
+
+impl NaiveJalaliDate {
+
+    /// Make a new date from jalali year, month and day
+    ///
+    /// Return `None` on invalid date (like 1402/12/30)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use persian_date::structure::NaiveJalaliDate;
+    ///
+    /// assert!(NaiveJalaliDate::from_ymd_opt(1403, 1, 15).is_some());
+    /// assert!(NaiveJalaliDate::from_ymd_opt(1402, 12, 30).is_none());
+    /// ```
+    pub const fn from_ymd_opt(year: i32, month: u32, day: u32) -> Option<Self> {
+        if !calendar::is_valid_jalali(year, month, day) {
+            return None;
+        }
+        Some(NaiveJalaliDate { year, month, day })
+    }
+
+    /// Convert gregorian date to jalali date
+    pub fn from_gregorian(date: NaiveDate) -> Self {
+        let (year, month, day) = calendar::gregorian_to_jalali(date.year(), date.month(), date.day());
+        NaiveJalaliDate { year, month, day }
+    }
+
+    /// Convert jalali date to gregorian date
+    pub fn to_gregorian(&self) -> NaiveDate {
+        let (year, month, day) = calendar::jalali_to_gregorian(self.year, self.month, self.day);
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    /// Make a date from Julian Day Number
+    ///
+    /// # Panics
+    ///
+    /// Panics when the date is out of supported range, see [from_jdn_opt](Self::from_jdn_opt)
+    pub const fn from_jdn(jdn: i64) -> Self {
+        match Self::from_jdn_opt(jdn) {
+            Some(date) => date,
+            None => panic!("Julian Day Number out of range"),
+        }
+    }
+
+    /// Make a date from Julian Day Number
+    ///
+    /// Return `None` outside [calendar::MIN_JDN]..=[calendar::MAX_JDN]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use persian_date::calendar;
+    /// use persian_date::structure::NaiveJalaliDate;
+    ///
+    /// assert_eq!(NaiveJalaliDate::from_jdn_opt(2460404).unwrap().to_string(), "1403-01-15");
+    /// assert_eq!(NaiveJalaliDate::from_jdn_opt(calendar::MAX_JDN).unwrap().year(), calendar::MAX_YEAR);
+    /// assert!(NaiveJalaliDate::from_jdn_opt(calendar::MAX_JDN + 1).is_none());
+    /// assert!(NaiveJalaliDate::from_jdn_opt(i64::MAX).is_none());
+    /// ```
+    pub const fn from_jdn_opt(jdn: i64) -> Option<Self> {
+        if jdn < calendar::MIN_JDN || jdn > calendar::MAX_JDN {
+            return None;
+        }
+        let (year, month, day) = calendar::jdn_to_jalali(jdn);
+        Some(NaiveJalaliDate { year, month, day })
+    }
+
+    /// Return Julian Day Number of date
+    pub const fn to_jdn(&self) -> i64 {
+        calendar::jalali_to_jdn(self.year, self.month, self.day)
+    }
+
+    /// Return the jalali year
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Return the jalali month
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Return the jalali day
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Return jalali year is leap or not
+    pub fn is_leap(&self) -> bool {
+        calendar::is_leap_year(self.year)
+    }
+
+    /// Return count of days in month
+    pub fn month_days(&self) -> u32 {
+        calendar::month_days(self.year, self.month)
+    }
+
+    /// Return day in year
+    pub fn day_of_year(&self) -> u32 {
+        calendar::day_of_year(self.month, self.day)
+    }
+
+    /// Return day in week (saturday is 0)
+    pub fn day_of_week(&self) -> u32 {
+        let weekday = self.to_gregorian().weekday();
+        if weekday == Weekday::Sat {
+            return 0
+        }
+        weekday.num_days_from_sunday() + 1
+    }
+
+    /// Return name of week day
+    #[cfg(feature = "alloc")]
+    pub fn day_name(&self) -> String {
+        Locale::Persian.day_name(self.day_of_week()).to_string()
+    }
+
+    /// Return name of month
+    #[cfg(feature = "alloc")]
+    pub fn month_name(&self) -> String {
+        Locale::Persian.month_name(self.month).to_string()
+    }
+
+    /// Return name of week day in a locale
+    pub fn day_name_in(&self, locale : Locale) -> &'static str {
+        locale.day_name(self.day_of_week())
+    }
+
+    /// Return name of month in a locale
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use persian_date::locale::Locale;
+    /// use persian_date::structure::NaiveJalaliDate;
+    ///
+    /// let date = NaiveJalaliDate::from_ymd_opt(1403, 1, 15).unwrap();
+    /// assert_eq!(date.month_name_in(Locale::Pashto), "وری");
+    /// assert_eq!(date.month_name_in(Locale::Kurdish), "خاکەلێوە");
+    /// assert_eq!(date.day_name_in(Locale::English), "Wednesday");
+    /// ```
+    pub fn month_name_in(&self, locale : Locale) -> &'static str {
+        locale.month_name(self.month)
+    }
+
+    /// Return year in an era
+    pub fn era_year(&self, era : Era) -> i32 {
+        era.from_jalali_year(self.year)
+    }
+
+    /// Return the zoroastrian fasli `(year, month, day)`, month is 13 for gatha days
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use persian_date::era::Era;
+    /// use persian_date::structure::NaiveJalaliDate;
+    ///
+    /// let date = NaiveJalaliDate::from_ymd_opt(1403, 12, 30).unwrap();
+    /// assert_eq!(date.fasli_date(), (1403, 13, 6));
+    /// assert_eq!(date.fasli_day_name(), "اورداد");
+    /// assert_eq!(date.era_year(Era::Zoroastrian), 3762);
+    /// ```
+    pub fn fasli_date(&self) -> (i32, u32, u32) {
+        calendar::jalali_to_fasli(self.year, self.month, self.day)
+    }
+
+    /// Return avestan name of fasli day
+    pub fn fasli_day_name(&self) -> &'static str {
+        let (_, month, day) = self.fasli_date();
+        era::fasli_day_name(month, day)
+    }
+
+    /// Return date in persian words, like `پانزدهم فروردین ماه یک هزار و چهارصد و سه`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use persian_date::structure::NaiveJalaliDate;
+    ///
+    /// let date = NaiveJalaliDate::from_ymd_opt(1403, 1, 15).unwrap();
+    /// assert_eq!(date.to_words(), "پانزدهم فروردین ماه یک هزار و چهارصد و سه");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_words(&self) -> String {
+        let mut out = String::new();
+        let _ = words::write_ordinal(&mut out, self.day as i64);
+        let _ = write!(out, " {} ماه ", Locale::Persian.month_name(self.month));
+        let _ = words::write_cardinal(&mut out, self.year as i64);
+        out
+    }
+}
+
+#[cfg(feature = "tz")]
 impl Reader for PDate {
     fn year(&self) -> i32 {
         self.year
@@ -192,7 +457,10 @@ impl Reader for PDate {
     }
 
     fn hour_12(&self) -> u32 {
-        if self.hour <= 12 { self.hour } else { self.hour - 12 }
+        match self.hour % 12 {
+            0 => 12,
+            hour => hour,
+        }
     }
 
     fn minute(&self) -> u32 {
@@ -216,11 +484,11 @@ impl Reader for PDate {
     }
 
     fn day_name(&self) -> String {
-        DAY_NAMES[self.day_of_week() as usize].to_string()
+        Locale::Persian.day_name(self.day_of_week()).to_string()
     }
 
     fn grg_day_name(&self) -> String {
-        EN_DAY_NAMES[self.day_of_week() as usize].to_string()
+        Locale::English.day_name(self.day_of_week()).to_string()
     }
 
     fn month_days(&self) -> u32 {
@@ -249,44 +517,19 @@ impl Reader for PDate {
     }
 
     fn month_name(&self) -> String {
-        MONTH_NAMES.get((self.month -1) as usize).expect("").to_string()
+        Locale::Persian.month_name(self.month).to_string()
     }
 
     fn grg_month_name(&self) -> String {
-        EN_MONTH_NAMES.get((self.grg_month -1) as usize).expect("").to_string()
+        Locale::English.grg_month_name(self.grg_month).to_string()
     }
 
     fn is_leap(&self) -> bool {
-        let ref_year = 1375f32;
-        let mut start_year = 1375f32;
-        let year_res : f32 = (self.year as f32) - ref_year;
-        if year_res == 0f32 || year_res % 33f32 == 0f32 {
-            return true
-        };
-        if year_res > 0f32 {
-            if year_res > 33f32 {
-                let numb = year_res / 33f32;
-                start_year = ref_year + (numb.floor() * 33f32);
-            }
-        } else if year_res > -33f32 {
-            start_year = ref_year - 33f32;
-        } else {
-            let numb = (year_res / 33f32).abs();
-            start_year = ref_year - (numb.ceil() * 33f32);
-        }
-        let leap_years = [start_year,start_year + 4f32,start_year + 8f32,
-            start_year + 12f32,start_year + 16f32,start_year + 20f32,start_year + 24f32,start_year + 28f32,start_year + 33f32];
-        leap_years.contains(&(self.year as f32))
+        calendar::is_leap_year(self.year)
     }
 
     fn is_grg_leap(&self) -> bool {
-        if self.grg_year % 4 == 0 {
-            if self.grg_year % 100 == 0 {
-                return self.grg_year % 400 == 0;
-            }
-            return true
-        }
-        false
+        calendar::is_grg_leap_year(self.grg_year)
     }
 
     fn day_of_week(&self) -> u32 {
@@ -322,120 +565,70 @@ impl Reader for PDate {
     }
 }
 
+#[cfg(feature = "tz")]
 impl Setter for PDate {
-    fn set_ymd(&mut self, year: i32, month: u32, day: u32) {
-        self.set_year(year);
-        self.set_month(month);
-        self.set_day(day)
+    fn set_ymd(&mut self, year: i32, month: u32, day: u32) -> Result<(), SetError> {
+        let (year, month, day) = check_jalali(year, month, day)?;
+        self.set_gregorian_date(year, month, day)
     }
 
-    fn set_grg_ymd(&mut self, year: i32, month: u32, day: u32) {
-        self.set_grg_year(year);
-        self.set_grg_month(month);
-        self.set_grg_day(day)
+    fn set_grg_ymd(&mut self, year: i32, month: u32, day: u32) -> Result<(), SetError> {
+        if !(calendar::MIN_GRG_YEAR..=calendar::MAX_GRG_YEAR).contains(&year) {
+            return Err(SetError::InvalidYear);
+        }
+        if !(1..=12).contains(&month) {
+            return Err(SetError::InvalidMonth);
+        }
+        if !calendar::is_valid_gregorian(year, month, day) {
+            return Err(SetError::InvalidDay);
+        }
+        self.set_gregorian_date(year, month, day)
     }
 
-    fn set_year(&mut self, year: i32) {
-        if year < 1 {
-            println!("Year must be greater than 0");
-            return;
-        }
-        let conv_date = Self::jalali_to_gregorian(year, self.month as i32, self.day as i32);
-        let date = self.get_date_from_jalali(&conv_date);
-        self.update_from_date(&date)
+    fn set_year(&mut self, year: i32) -> Result<(), SetError> {
+        self.set_ymd(year, self.month, self.day)
     }
 
-    fn set_grg_year(&mut self, year: i32) {
-        if year < 1 {
-            println!("Year must be greater than 0");
-            return;
-        }
-        let date = self.get_zoned_date();
-        let date = date.with_year(year).unwrap();
-        self.update_from_date(&date)
+    fn set_grg_year(&mut self, year: i32) -> Result<(), SetError> {
+        self.set_grg_ymd(year, self.grg_month, self.grg_day)
     }
 
-    fn set_month(&mut self, month: u32) {
-        if !(1..=12).contains(&month) {
-            println!("Month must be between 1 and 12");
-            return;
-        }
-        let conv_date = Self::jalali_to_gregorian(self.year, month as i32, self.day as i32);
-        let date = self.get_date_from_jalali(&conv_date);
-        self.update_from_date(&date)
+    fn set_month(&mut self, month: u32) -> Result<(), SetError> {
+        self.set_ymd(self.year, month, self.day)
     }
 
-    fn set_grg_month(&mut self, month: u32) {
-        if !(1..=12).contains(&month) {
-            println!("Month must be between 1 and 12");
-            return;
-        }
-        let date = self.get_zoned_date();
-        let date = date.with_month(month).unwrap();
-        self.update_from_date(&date)
+    fn set_grg_month(&mut self, month: u32) -> Result<(), SetError> {
+        self.set_grg_ymd(self.grg_year, month, self.grg_day)
     }
 
-    fn set_day(&mut self, day: u32) {
-        if !(1..=31).contains(&day) {
-            println!("Day must be between 1 and 31");
-            return;
-        }
-        if day > self.month_days() {
-            println!("Day must be between 1-{}",self.month_days());
-            return;
-        }
-        let conv_date = Self::jalali_to_gregorian(self.year, self.month as i32, day as i32);
-        let date = self.get_date_from_jalali(&conv_date);
-        self.update_from_date(&date)
+    fn set_day(&mut self, day: u32) -> Result<(), SetError> {
+        self.set_ymd(self.year, self.month, day)
     }
 
-    fn set_grg_day(&mut self, day: u32) {
-        if !(1..=31).contains(&day) {
-            println!("Day must be between 1 and 31");
-            return;
-        }
-        if day > self.grg_month_days() {
-            println!("Day must be between 1-{}",self.grg_month_days());
-            return;
-        }
-        let date = self.get_zoned_date();
-        let date = date.with_day(day).unwrap();
-        self.update_from_date(&date)
+    fn set_grg_day(&mut self, day: u32) -> Result<(), SetError> {
+        self.set_grg_ymd(self.grg_year, self.grg_month, day)
     }
 
-    fn set_hour(&mut self, hour: u32) {
-        if hour > 23 {
-            println!("Hour must be between 0 and 23");
-            return;
-        }
-        let date = self.get_zoned_date();
-        let date = date.with_hour(hour).unwrap();
-        self.update_from_date(&date)
+    fn set_hour(&mut self, hour: u32) -> Result<(), SetError> {
+        self.set_time(hour, self.minute, self.second)
     }
 
-    fn set_minute(&mut self, minute: u32) {
-        if minute > 59 {
-            println!("Minute must be between 0 and 59");
-            return;
-        }
-        let date = self.get_zoned_date();
-        let date = date.with_minute(minute).unwrap();
-        self.update_from_date(&date)
+    fn set_minute(&mut self, minute: u32) -> Result<(), SetError> {
+        self.set_time(self.hour, minute, self.second)
     }
 
-    fn set_second(&mut self, second: u32) {
-        if second > 59 {
-            println!("Second must be between 0 and 59");
-            return;
-        }
-        let date = self.get_zoned_date();
-        let date = date.with_second(second).unwrap();
-        self.update_from_date(&date)
+    fn set_second(&mut self, second: u32) -> Result<(), SetError> {
+        self.set_time(self.hour, self.minute, second)
     }
 
-    fn set_time_millis(&mut self, millis: i64) {
-        let date = self.time_zone.timestamp_millis_opt(millis).unwrap();
-        self.update_from_date(&date)
+    fn set_time_millis(&mut self, millis: i64) -> Result<(), SetError> {
+        let date = self.time_zone.timestamp_millis_opt(millis).single().ok_or(SetError::OutOfRange)?;
+        let year = calendar::gregorian_to_jalali(date.year(), date.month(), date.day()).0;
+        if !(calendar::MIN_YEAR..=calendar::MAX_YEAR).contains(&year) {
+            return Err(SetError::OutOfRange);
+        }
+        self.update_from_date(&date);
+        Ok(())
     }
 
     fn set_time_zone(&mut self, timezone: Tz) {
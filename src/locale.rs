@@ -0,0 +1,177 @@
+//! Locale tables for names and digits
+//!
+//! # Example
+//!
+//! ```
+//! use persian_date::locale::{Digits, Locale};
+//!
+//! assert_eq!(Locale::Persian.month_name(2), "اردیبهشت");
+//! assert_eq!(Locale::Dari.month_name(2), "ثور");
+//! assert_eq!(Locale::English.month_name(2), "Ordibehesht");
+//! assert_eq!(Locale::Persian.grg_month_name(4), "آوریل");
+//! assert_eq!(Digits::Persian.convert('7'), '۷');
+//! ```
+
+use core::fmt::Write;
+
+/// Language of month and week day names
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Locale {
+    /// Persian (fa), names like فروردین، اردیبهشت
+    #[default]
+    Persian,
+    /// Dari (fa-AF), zodiac month names like حمل، ثور
+    Dari,
+    /// Pashto (ps), month names like وری، غویی
+    Pashto,
+    /// Kurdish Sorani (ckb), month names like خاکەلێوە، گوڵان
+    Kurdish,
+    /// English transliteration, month names like Farvardin, Ordibehesht
+    English,
+}
+
+pub(crate) const PERSIAN_MONTH_NAMES : [&str; 12] = ["فروردین", "اردیبهشت", "خرداد", "تیر", "مرداد", "شهریور", "مهر", "آبان", "آذر", "دی", "بهمن", "اسفند"];
+const DARI_MONTH_NAMES : [&str; 12] = ["حمل", "ثور", "جوزا", "سرطان", "اسد", "سنبله", "میزان", "عقرب", "قوس", "جدی", "دلو", "حوت"];
+const PASHTO_MONTH_NAMES : [&str; 12] = ["وری", "غویی", "غبرگولی", "چنګاښ", "زمری", "وږی", "تله", "لړم", "لیندۍ", "مرغومی", "سلواغه", "کب"];
+const KURDISH_MONTH_NAMES : [&str; 12] = ["خاکەلێوە", "گوڵان", "جۆزەردان", "پووشپەڕ", "گەلاوێژ", "خەرمانان", "ڕەزبەر", "گەڵاڕێزان", "سەرماوەز", "بەفرانبار", "ڕێبەندان", "ڕەشەمە"];
+const ENGLISH_MONTH_NAMES : [&str; 12] = ["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"];
+
+pub(crate) const PERSIAN_DAY_NAMES : [&str; 7] = ["شنبه", "یک‌شنبه", "دوشنبه", "سه‌شنبه", "چهارشنبه", "پنج‌شنبه", "جمعه"];
+#[cfg(feature = "tz")]
+pub(crate) const PERSIAN_SHORT_DAY_NAMES : [&str; 7] = ["ش", "ی", "د", "س", "چ", "پ", "ج"];
+const PASHTO_DAY_NAMES : [&str; 7] = ["اونۍ", "يونۍ", "دونۍ", "درېنۍ", "څلرنۍ", "پينځنۍ", "جمعه"];
+const KURDISH_DAY_NAMES : [&str; 7] = ["شەممە", "یەکشەممە", "دووشەممە", "سێشەممە", "چوارشەممە", "پێنجشەممە", "ھەینی"];
+pub(crate) const ENGLISH_DAY_NAMES : [&str; 7] = ["Saturday", "Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday"];
+
+const PERSIAN_GRG_MONTH_NAMES : [&str; 12] = ["ژانویه", "فوریه", "مارس", "آوریل", "مه", "ژوئن", "ژوئیه", "اوت", "سپتامبر", "اکتبر", "نوامبر", "دسامبر"];
+const DARI_GRG_MONTH_NAMES : [&str; 12] = ["جنوری", "فبروری", "مارچ", "اپریل", "می", "جون", "جولای", "اگست", "سپتمبر", "اکتوبر", "نومبر", "دسمبر"];
+const PASHTO_GRG_MONTH_NAMES : [&str; 12] = ["جنوري", "فبروري", "مارچ", "اپریل", "مۍ", "جون", "جولای", "اګست", "سېپتمبر", "اکتوبر", "نومبر", "دسمبر"];
+const KURDISH_GRG_MONTH_NAMES : [&str; 12] = ["کانوونی دووەم", "شوبات", "ئازار", "نیسان", "ئایار", "حوزەیران", "تەمووز", "ئاب", "ئەیلوول", "تشرینی یەکەم", "تشرینی دووەم", "کانوونی یەکەم"];
+pub(crate) const ENGLISH_GRG_MONTH_NAMES : [&str; 12] = ["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"];
+
+#[cfg(feature = "tz")]
+pub(crate) const HIJRI_MONTH_NAMES : [&str; 12] = ["محرم", "صفر", "ربیع‌الاول", "ربیع‌الثانی", "جمادی‌الاول", "جمادی‌الثانی", "رجب", "شعبان", "رمضان", "شوال", "ذی‌القعده", "ذی‌الحجه"];
+
+impl Locale {
+    /// Return name of jalali month (1 to 12)
+    ///
+    /// # Panics
+    ///
+    /// Panics when month is not between 1 and 12
+    pub fn month_name(&self, month : u32) -> &'static str {
+        assert!((1..=12).contains(&month), "month must be between 1 and 12");
+        let names = match self {
+            Locale::Persian => &PERSIAN_MONTH_NAMES,
+            Locale::Dari => &DARI_MONTH_NAMES,
+            Locale::Pashto => &PASHTO_MONTH_NAMES,
+            Locale::Kurdish => &KURDISH_MONTH_NAMES,
+            Locale::English => &ENGLISH_MONTH_NAMES,
+        };
+        names[(month - 1) as usize]
+    }
+
+    /// Return name of week day (saturday is 0)
+    ///
+    /// # Panics
+    ///
+    /// Panics when day of week is not between 0 and 6
+    pub fn day_name(&self, day_of_week : u32) -> &'static str {
+        assert!(day_of_week < 7, "day of week must be between 0 and 6");
+        let names = match self {
+            Locale::Persian | Locale::Dari => &PERSIAN_DAY_NAMES,
+            Locale::Pashto => &PASHTO_DAY_NAMES,
+            Locale::Kurdish => &KURDISH_DAY_NAMES,
+            Locale::English => &ENGLISH_DAY_NAMES,
+        };
+        names[day_of_week as usize]
+    }
+
+    /// Return name of gregorian month (1 to 12)
+    ///
+    /// # Panics
+    ///
+    /// Panics when month is not between 1 and 12
+    pub fn grg_month_name(&self, month : u32) -> &'static str {
+        assert!((1..=12).contains(&month), "month must be between 1 and 12");
+        let names = match self {
+            Locale::Persian => &PERSIAN_GRG_MONTH_NAMES,
+            Locale::Dari => &DARI_GRG_MONTH_NAMES,
+            Locale::Pashto => &PASHTO_GRG_MONTH_NAMES,
+            Locale::Kurdish => &KURDISH_GRG_MONTH_NAMES,
+            Locale::English => &ENGLISH_GRG_MONTH_NAMES,
+        };
+        names[(month - 1) as usize]
+    }
+
+    /// Return abbreviation of solar hijri era
+    pub fn era(&self) -> &'static str {
+        match self {
+            Locale::Persian | Locale::Dari => "ه\u{200d}.ش.",
+            Locale::Pashto => "ل.ه.",
+            Locale::Kurdish => "ھەتاوی",
+            Locale::English => "AP",
+        }
+    }
+
+    /// Return digits used by the locale
+    pub fn digits(&self) -> Digits {
+        match self {
+            Locale::Persian | Locale::Dari | Locale::Pashto => Digits::Persian,
+            Locale::Kurdish => Digits::Arabic,
+            Locale::English => Digits::Latin,
+        }
+    }
+
+    /// Return text between date and time in long styles
+    #[cfg(feature = "tz")]
+    pub(crate) fn time_separator(&self, long : bool) -> &'static str {
+        match (self, long) {
+            (Locale::Persian | Locale::Dari, true) => "، ساعت ",
+            (Locale::English, true) => " at ",
+            (Locale::English, false) => ", ",
+            _ => "، ",
+        }
+    }
+}
+
+/// Digit system of numbers
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Digits {
+    /// 0123456789
+    #[default]
+    Latin,
+    /// ۰۱۲۳۴۵۶۷۸۹
+    Persian,
+    /// ٠١٢٣٤٥٦٧٨٩
+    Arabic,
+}
+
+impl Digits {
+    /// Convert an ascii digit to this digit system, other characters are returned as is
+    pub fn convert(&self, c : char) -> char {
+        let zero = match self {
+            Digits::Latin => return c,
+            Digits::Persian => '۰',
+            Digits::Arabic => '٠',
+        };
+        match c.to_digit(10) {
+            Some(digit) if c.is_ascii_digit() => char::from_u32(zero as u32 + digit).unwrap_or(c),
+            _ => c,
+        }
+    }
+}
+
+/// Writer which converts ascii digits to a digit system on the fly
+pub(crate) struct DigitsWriter<'a, W : Write + ?Sized> {
+    pub(crate) inner : &'a mut W,
+    pub(crate) digits : Digits,
+}
+
+impl<W : Write + ?Sized> Write for DigitsWriter<'_, W> {
+    fn write_str(&mut self, s : &str) -> core::fmt::Result {
+        if self.digits == Digits::Latin {
+            return self.inner.write_str(s);
+        }
+        s.chars().try_for_each(|c| self.inner.write_char(self.digits.convert(c)))
+    }
+}
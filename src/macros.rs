@@ -0,0 +1,82 @@
+/// Make a [NaiveJalaliDate](crate::structure::NaiveJalaliDate) from a date literal
+///
+/// The date is validated at compile time, so invalid dates fail to build
+///
+/// # Example
+///
+/// ```
+/// use persian_date::jdate;
+///
+/// let date = jdate!(1403-01-15);
+/// assert_eq!(date.to_string(), "1403-01-15");
+///
+/// const NOWRUZ : [persian_date::structure::NaiveJalaliDate; 2] = [jdate!(1403/01/01), jdate!(1404/01/01)];
+/// ```
+///
+/// ```compile_fail
+/// use persian_date::jdate;
+///
+/// // esfand 1402 has 29 days
+/// let date = jdate!(1402-12-30);
+/// ```
+#[macro_export]
+macro_rules! jdate {
+    ($year:literal - $month:literal - $day:literal) => {
+        $crate::jdate!(@checked $year, $month, $day)
+    };
+    ($year:literal / $month:literal / $day:literal) => {
+        $crate::jdate!(@checked $year, $month, $day)
+    };
+    (@checked $year:literal, $month:literal, $day:literal) => {{
+        const DATE : $crate::structure::NaiveJalaliDate = match $crate::structure::NaiveJalaliDate::from_ymd_opt($year, $month, $day) {
+            Some(date) => date,
+            None => panic!(concat!("invalid jalali date ", stringify!($year), "/", stringify!($month), "/", stringify!($day))),
+        };
+        DATE
+    }};
+}
+
+/// Make a [PDate](crate::structure::PDate) from a date-time literal and optional timezone (default is Iran)
+///
+/// The date and time are validated at compile time, so invalid values fail to build
+///
+/// # Example
+///
+/// ```
+/// use chrono_tz::Tz;
+/// use persian_date::jdatetime;
+/// use persian_date::structure::Reader;
+///
+/// let pdate = jdatetime!(1403-01-15 09:30:00);
+/// assert_eq!(pdate.to_string(), "1403-01-15 09:30:00 +03:30");
+///
+/// let pdate = jdatetime!(1403-01-15 09:30:00, Tz::Asia__Kabul);
+/// assert_eq!(pdate.hour(), 9);
+/// ```
+///
+/// ```compile_fail
+/// use persian_date::jdatetime;
+///
+/// let pdate = jdatetime!(1403-01-15 24:30:00);
+/// ```
+#[cfg(feature = "tz")]
+#[macro_export]
+macro_rules! jdatetime {
+    ($year:literal - $month:literal - $day:literal $hour:literal : $minute:literal : $second:literal $(, $time_zone:expr)?) => {
+        $crate::jdatetime!(@checked $year, $month, $day, $hour, $minute, $second $(, $time_zone)?)
+    };
+    ($year:literal / $month:literal / $day:literal $hour:literal : $minute:literal : $second:literal $(, $time_zone:expr)?) => {
+        $crate::jdatetime!(@checked $year, $month, $day, $hour, $minute, $second $(, $time_zone)?)
+    };
+    (@checked $year:literal, $month:literal, $day:literal, $hour:literal, $minute:literal, $second:literal) => {
+        $crate::jdatetime!(@checked $year, $month, $day, $hour, $minute, $second, $crate::__private::Tz::Iran)
+    };
+    (@checked $year:literal, $month:literal, $day:literal, $hour:literal, $minute:literal, $second:literal, $time_zone:expr) => {{
+        const TIME : (u32, u32, u32) = if $hour < 24 && $minute < 60 && $second < 60 {
+            ($hour, $minute, $second)
+        } else {
+            panic!(concat!("invalid time ", stringify!($hour), ":", stringify!($minute), ":", stringify!($second)))
+        };
+        $crate::structure::PDate::from_naive_jalali($crate::jdate!(@checked $year, $month, $day), TIME.0, TIME.1, TIME.2, $time_zone)
+    }};
+}
@@ -0,0 +1,178 @@
+//! [moment-jalaali](https://github.com/jalaali/moment-jalaali) / jalali-moment compatible patterns
+//!
+//! Tokens with `j` prefix are jalali fields, tokens without it are gregorian fields (like moment-jalaali)
+//!
+//! | Token | Meaning |
+//! |-------|---------|
+//! | `jYYYY` / `YYYY` | jalali / gregorian year |
+//! | `jYY` / `YY` | jalali / gregorian year modulo 100 |
+//! | `jM` / `jMM` | jalali month, `jMM` zero padded |
+//! | `jMMM` / `jMMMM` | short / full jalali month name (short names are full names in persian) |
+//! | `M` / `MM` / `MMM` / `MMMM` | gregorian month, zero padded, short name, name |
+//! | `jD` / `jDD` | jalali day, `jDD` zero padded |
+//! | `D` / `DD` | gregorian day, `DD` zero padded |
+//! | `jDo` / `Do` | jalali / gregorian ordinal day in persian words, like `پانزدهم` |
+//! | `jDDD` / `jDDDD` | day of jalali year, `jDDDD` zero padded |
+//! | `DDD` / `DDDD` | day of gregorian year, `DDDD` zero padded |
+//! | `d` / `e` | day in week from sunday / saturday |
+//! | `dd` / `ddd` / `dddd` | min / short / full week day name (min and short are one letter in persian) |
+//! | `H` / `HH` / `h` / `hh` | hour, 24 / 12 time format |
+//! | `m` / `mm` / `s` / `ss` | minute / second |
+//! | `SSS` | milliseconds |
+//! | `A` / `a` | time of day (ق.ظ - ب.ظ) |
+//! | `Z` / `ZZ` | timezone offset like `+03:30` / `+0330` |
+//! | `X` / `x` | unix timestamp in seconds / milliseconds |
+//! | `[text]` | literal text |
+//!
+//! # Example
+//!
+//! ```
+//! use persian_date::structure::PDate;
+//!
+//! let pdate = PDate::from_time_millis(1712136600000);
+//! assert_eq!(pdate.format_moment("jYYYY/jMM/jDD HH:mm").to_string(), "1403/01/15 13:00");
+//! assert_eq!(pdate.format_moment("dddd jD jMMMM [ساعت] H").to_string(), "چهارشنبه 15 فروردین ساعت 13");
+//! assert_eq!(pdate.format_moment("jDo jMMMM، Do MMMM").to_string(), "پانزدهم فروردین، سوم April");
+//!
+//! let parsed = PDate::parse_moment("1403/01/15 13:00", "jYYYY/jMM/jDD HH:mm").unwrap();
+//! assert_eq!(parsed, pdate);
+//! ```
+
+use chrono_tz::Tz;
+use crate::format::{DelayedFormat, Directive, Item, Modifier, Pad};
+use crate::locale::Locale;
+use crate::parse::{self, ParseError};
+use crate::structure::{NaiveJalaliDate, PDate};
+
+/// Tokens of moment patterns, the longest matching token wins
+const TOKENS : [(&str, Directive, Pad); 45] = [
+    ("jYYYY", Directive::Year, Pad::Zero),
+    ("jMMMM", Directive::MonthName, Pad::Default),
+    ("jDDDD", Directive::DayOfYear, Pad::Default),
+    ("jMMM", Directive::ShortMonthName, Pad::Default),
+    ("jDDD", Directive::DayOfYear, Pad::None),
+    ("YYYY", Directive::GrgYear, Pad::Zero),
+    ("MMMM", Directive::GrgMonthName, Pad::Default),
+    ("DDDD", Directive::GrgDayOfYear, Pad::Default),
+    ("dddd", Directive::DayName, Pad::Default),
+    ("jYY", Directive::YearMod100, Pad::Default),
+    ("jMM", Directive::Month, Pad::Default),
+    ("jDD", Directive::Day, Pad::Default),
+    ("MMM", Directive::GrgShortMonthName, Pad::Default),
+    ("DDD", Directive::GrgDayOfYear, Pad::None),
+    ("ddd", Directive::ShortDayName, Pad::Default),
+    ("jDo", Directive::DayOrdinal, Pad::Default),
+    ("SSS", Directive::Millisecond, Pad::Default),
+    ("jM", Directive::Month, Pad::None),
+    ("jD", Directive::Day, Pad::None),
+    ("YY", Directive::GrgYearMod100, Pad::Default),
+    ("MM", Directive::GrgMonth, Pad::Default),
+    ("DD", Directive::GrgDay, Pad::Default),
+    ("dd", Directive::MinDayName, Pad::Default),
+    ("Do", Directive::GrgDayOrdinal, Pad::Default),
+    ("HH", Directive::Hour, Pad::Default),
+    ("hh", Directive::Hour12, Pad::Default),
+    ("mm", Directive::Minute, Pad::Default),
+    ("ss", Directive::Second, Pad::Default),
+    ("ZZ", Directive::TimeZoneCompact, Pad::Default),
+    ("M", Directive::GrgMonth, Pad::None),
+    ("D", Directive::GrgDay, Pad::None),
+    ("d", Directive::GrgDayOfWeek, Pad::Default),
+    ("e", Directive::DayOfWeek, Pad::Default),
+    ("H", Directive::Hour, Pad::None),
+    ("h", Directive::Hour12, Pad::None),
+    ("m", Directive::Minute, Pad::None),
+    ("s", Directive::Second, Pad::None),
+    ("A", Directive::ShortTimeOfDay, Pad::Default),
+    ("a", Directive::ShortTimeOfDay, Pad::Default),
+    ("Z", Directive::TimeZone, Pad::Default),
+    ("X", Directive::Timestamp, Pad::Default),
+    ("x", Directive::TimestampMillis, Pad::Default),
+    // moment has no hijri tokens, these follow moment-hijri `i` prefix
+    ("iYYYY", Directive::HijriYear, Pad::Default),
+    ("iMM", Directive::HijriMonth, Pad::Default),
+    ("iDD", Directive::HijriDay, Pad::Default),
+];
+
+/// Tokenizer of moment pattern, iterates over format [Item]s without allocation
+#[derive(Clone, Debug)]
+pub struct MomentItems<'a> {
+    remainder : &'a str,
+}
+
+impl<'a> MomentItems<'a> {
+    pub fn new(pattern : &'a str) -> Self {
+        MomentItems { remainder: pattern }
+    }
+}
+
+fn token_at(s : &str) -> Option<(usize, Item<'static>)> {
+    TOKENS.iter()
+        .filter(|(token, _, _)| s.starts_with(token))
+        .max_by_key(|(token, _, _)| token.len())
+        .map(|(token, directive, pad)| (token.len(), Item::Directive(*directive, Modifier { pad: *pad, uppercase: false })))
+}
+
+impl<'a> Iterator for MomentItems<'a> {
+    type Item = Item<'a>;
+
+    fn next(&mut self) -> Option<Item<'a>> {
+        if self.remainder.is_empty() {
+            return None;
+        }
+        if let Some(escaped) = self.remainder.strip_prefix('[') {
+            let end = escaped.find(']').unwrap_or(escaped.len());
+            self.remainder = escaped.get(end + 1..).unwrap_or("");
+            return Some(Item::Literal(&escaped[..end]));
+        }
+        if let Some((len, item)) = token_at(self.remainder) {
+            self.remainder = &self.remainder[len..];
+            return Some(item);
+        }
+        let end = self.remainder.char_indices()
+            .find(|(index, c)| *c == '[' || token_at(&self.remainder[*index..]).is_some())
+            .map_or(self.remainder.len(), |(index, _)| index);
+        let (literal, remainder) = self.remainder.split_at(end);
+        self.remainder = remainder;
+        Some(Item::Literal(literal))
+    }
+}
+
+impl PDate {
+    /// Format lazily with moment-jalaali pattern
+    pub fn format_moment<'a>(&'a self, pattern : &'a str) -> DelayedFormat<'a, MomentItems<'a>> {
+        DelayedFormat::new(self, MomentItems::new(pattern))
+    }
+
+    /// Format lazily with moment-jalaali pattern and month and day names of a locale
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use persian_date::locale::Locale;
+    /// use persian_date::structure::PDate;
+    ///
+    /// let pdate = PDate::from_time_millis(1712136600000);
+    /// assert_eq!(pdate.format_moment_localized("dd, ddd, dddd jD jMMM", Locale::English).to_string(), "We, Wed, Wednesday 15 Far");
+    /// ```
+    pub fn format_moment_localized<'a>(&'a self, pattern : &'a str, locale : Locale) -> DelayedFormat<'a, MomentItems<'a>> {
+        DelayedFormat::new_localized(self, MomentItems::new(pattern), locale)
+    }
+
+    /// Parse date from string with moment-jalaali pattern, in default timezone (Iran)
+    pub fn parse_moment(input : &str, pattern : &str) -> Result<PDate, ParseError> {
+        parse::parse(input, MomentItems::new(pattern), Tz::Iran)
+    }
+
+    /// Parse date from string with moment-jalaali pattern, local times are in given timezone
+    pub fn parse_moment_tz(input : &str, pattern : &str, time_zone : Tz) -> Result<PDate, ParseError> {
+        parse::parse(input, MomentItems::new(pattern), time_zone)
+    }
+}
+
+impl NaiveJalaliDate {
+    /// Parse date from string with moment-jalaali pattern, time fields are ignored
+    pub fn parse_moment(input : &str, pattern : &str) -> Result<NaiveJalaliDate, ParseError> {
+        parse::parse_date(input, MomentItems::new(pattern))
+    }
+}
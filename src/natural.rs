@@ -0,0 +1,359 @@
+//! Parse natural persian date phrases, like `فردا`، `سه روز بعد` or `شنبه آینده`
+//!
+//! Phrases are resolved against a reference date in its time zone, supported forms are
+//!
+//! | Phrase | Example |
+//! |--------|---------|
+//! | relative days | `امروز`، `فردا`، `پس‌فردا`، `دیروز`، `پریروز` |
+//! | distance | `۳ روز بعد`، `دو هفته پیش`، `یه ماه دیگه`، `۲ ساعت بعد` |
+//! | week day | `شنبه`، `شنبه آینده`، `دوشنبه گذشته` |
+//! | day of month | `۱۵ فروردین`، `اول اردیبهشت`، `پانزدهم مهر ماه ۱۴۰۲`، `1403/01/15` |
+//! | whole periods | `این هفته`، `ماه آینده`، `پارسال`، `امسال` |
+//! | time of day | `ساعت ۵ عصر`، `ساعت ۱۴:۳۰`، `ساعت ۸ و نیم صبح`، `فردا صبح` |
+//!
+//! Persian, arabic and latin digits, numbers in words and colloquial forms (`دیگه`، `یه`) are accepted
+//!
+//! # Example
+//!
+//! ```
+//! use persian_date::jdatetime;
+//! use persian_date::natural::NaturalDate;
+//! use persian_date::structure::PDate;
+//!
+//! let reference = jdatetime!(1403-01-15 09:30:00);
+//!
+//! let date = PDate::parse_natural("پس‌فردا ساعت ۵ عصر", &reference).unwrap();
+//! assert_eq!(date.start().to_string(), "1403-01-17 17:00:00 +03:30");
+//!
+//! let date = PDate::parse_natural("اول اردیبهشت", &reference).unwrap();
+//! assert_eq!(date.start().to_string(), "1403-02-01 00:00:00 +03:30");
+//! assert!(matches!(date, NaturalDate::Range(..)));
+//!
+//! let date = PDate::parse_natural("دو هفته پیش", &reference).unwrap();
+//! assert_eq!(date.start().jalali_date().to_string(), "1403-01-01");
+//!
+//! let NaturalDate::Range(start, end) = PDate::parse_natural("هفته آینده", &reference).unwrap() else { panic!() };
+//! assert_eq!((start.jalali_date().to_string(), end.jalali_date().to_string()), ("1403-01-18".into(), "1403-01-25".into()));
+//!
+//! let date = PDate::parse_natural("3 ساعت بعد", &reference).unwrap();
+//! assert_eq!(date.start().to_string(), "1403-01-15 12:30:00 +03:30");
+//! ```
+
+use alloc::string::String;
+use chrono::Duration;
+use crate::calendar;
+use crate::locale::Locale;
+use crate::parse::ParseError;
+use crate::structure::{NaiveJalaliDate, PDate, Setter};
+use crate::words;
+
+/// Result of natural date phrase
+#[derive(Debug)]
+pub enum NaturalDate {
+    /// Exact moment, when phrase has a time of day or a distance in hours and minutes
+    Instant(PDate),
+    /// Range of whole days, start is inclusive and end is exclusive
+    Range(PDate, PDate),
+}
+
+impl NaturalDate {
+    /// Return the moment or start of range
+    pub fn start(&self) -> &PDate {
+        match self {
+            NaturalDate::Instant(date) | NaturalDate::Range(date, _) => date,
+        }
+    }
+}
+
+/// Date part of phrase
+enum DatePart {
+    Day(NaiveJalaliDate),
+    /// Days from start (inclusive) to end (exclusive)
+    Days(NaiveJalaliDate, NaiveJalaliDate),
+    Offset(Duration),
+}
+
+const FUTURE : [&str; 5] = ["آینده", "بعد", "دیگر", "دیگه", "بعدی"];
+const PAST : [&str; 4] = ["گذشته", "پیش", "قبل", "قبلی"];
+
+/// Parse natural date phrase against reference date
+pub fn parse_natural(input : &str, reference : &PDate) -> Result<NaturalDate, ParseError> {
+    let input = normalize(input);
+    let s = input.as_str();
+    if s.is_empty() {
+        return Err(ParseError::TooShort);
+    }
+    let today = reference.jalali_date();
+    let (date, s) = match take_date(s, &today) {
+        Some((date, s)) => (Some(date?), s.trim_start()),
+        None => (None, s),
+    };
+    let (time, s) = match take_time(s) {
+        Some((time, s)) => (Some(time), s.trim_start()),
+        None => (None, s),
+    };
+    if !s.is_empty() {
+        return Err(if date.is_none() && time.is_none() { ParseError::Invalid } else { ParseError::TooLong });
+    }
+    let at = |date : NaiveJalaliDate, (hour, minute) : (u32, u32)| PDate::from_naive_jalali(date, hour, minute, 0, reference.time_zone);
+    Ok(match (date, time) {
+        (None, None) => return Err(ParseError::Invalid),
+        (Some(DatePart::Offset(offset)), None) => {
+            let millis = reference.time_millis.checked_add(offset.num_milliseconds()).ok_or(ParseError::OutOfRange)?;
+            let mut date = PDate { time_zone: reference.time_zone, ..PDate::default() };
+            date.set_time_millis(millis).map_err(|_| ParseError::OutOfRange)?;
+            NaturalDate::Instant(date)
+        }
+        (Some(DatePart::Offset(_)) | Some(DatePart::Days(..)), Some(_)) => return Err(ParseError::Invalid),
+        (Some(DatePart::Day(date)), Some(time)) => NaturalDate::Instant(at(date, time)),
+        (None, Some(time)) => NaturalDate::Instant(at(today, time)),
+        (Some(DatePart::Day(date)), None) => NaturalDate::Range(at(date, (0, 0)), at(add_days(&date, 1)?, (0, 0))),
+        (Some(DatePart::Days(start, end)), None) => NaturalDate::Range(at(start, (0, 0)), at(end, (0, 0))),
+    })
+}
+
+impl PDate {
+    /// Parse natural persian date phrase against reference date, see [natural](crate::natural)
+    pub fn parse_natural(input : &str, reference : &PDate) -> Result<NaturalDate, ParseError> {
+        parse_natural(input, reference)
+    }
+}
+
+/// Convert digits to ascii, arabic letters to persian, zero width non-joiners and repeated spaces to one space
+fn normalize(input : &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for c in input.trim().chars() {
+        let c = match c {
+            '۰'..='۹' => char::from_u32(c as u32 - '۰' as u32 + '0' as u32).unwrap_or(c),
+            '٠'..='٩' => char::from_u32(c as u32 - '٠' as u32 + '0' as u32).unwrap_or(c),
+            'ي' | 'ى' => 'ی',
+            'ك' => 'ک',
+            '\u{200c}' | '\u{200f}' | '\u{200e}' => ' ',
+            c if c.is_whitespace() => ' ',
+            c => c,
+        };
+        if c == ' ' && (out.is_empty() || out.ends_with(' ')) {
+            continue;
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Strip a whole word (or phrase) from start of input
+fn take_word<'s>(s : &'s str, word : &str) -> Option<&'s str> {
+    let rest = s.strip_prefix(word)?;
+    if rest.chars().next().is_some_and(char::is_alphanumeric) {
+        return None;
+    }
+    Some(rest.trim_start())
+}
+
+fn take_any<'s>(s : &'s str, words : &[&str]) -> Option<(usize, &'s str)> {
+    words.iter().enumerate()
+        .filter_map(|(index, word)| take_word(s, word).map(|rest| (index, word.len(), rest)))
+        .max_by_key(|(_, len, _)| *len)
+        .map(|(index, _, rest)| (index, rest))
+}
+
+/// Read a number in digits or words
+fn take_count(s : &str) -> Option<(i64, &str)> {
+    let digits = s.bytes().take_while(u8::is_ascii_digit).count();
+    if digits > 0 {
+        let value = s[..digits].parse().ok()?;
+        return Some((value, s[digits..].trim_start()));
+    }
+    if let Some(rest) = take_word(s, "یه") {
+        return Some((1, rest));
+    }
+    words::take_number(s).map(|(value, rest)| (value, rest.trim_start()))
+}
+
+/// Read direction word, `1` for future and `-1` for past
+fn take_direction(s : &str) -> Option<(i64, &str)> {
+    take_any(s, &FUTURE).map(|(_, s)| (1, s))
+        .or_else(|| take_any(s, &PAST).map(|(_, s)| (-1, s)))
+}
+
+/// Phrase which is matched, but may resolve to a date out of supported range
+type Taken<'s> = Option<(Result<DatePart, ParseError>, &'s str)>;
+
+fn take_date<'s>(s : &'s str, today : &NaiveJalaliDate) -> Taken<'s> {
+    const DAYS : [(&str, i64); 9] = [("امروز", 0), ("امشب", 0), ("فردا", 1), ("پس فردا", 2), ("پسفردا", 2),
+        ("دیروز", -1), ("دیشب", -1), ("پریروز", -2), ("پریشب", -2)];
+    let names = DAYS.map(|(name, _)| name);
+    if let Some((index, rest)) = take_any(s, &names) {
+        return Some((add_days(today, DAYS[index].1).map(DatePart::Day), rest));
+    }
+    take_period(s, today)
+        .or_else(|| take_week_day(s, today))
+        .or_else(|| take_distance(s, today))
+        .or_else(|| take_day_of_month(s, today))
+}
+
+/// Whole week, month or year like `هفته آینده` or `امسال`
+fn take_period<'s>(s : &'s str, today : &NaiveJalaliDate) -> Taken<'s> {
+    const YEARS : [(&str, i64); 4] = [("امسال", 0), ("پارسال", -1), ("پیرارسال", -2), ("سال دیگه", 1)];
+    if let Some((index, rest)) = take_any(s, &YEARS.map(|(name, _)| name)) {
+        return Some((year_range(today.year as i64 + YEARS[index].1), rest));
+    }
+    let (this, rest) = match take_word(s, "این") {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    let (unit, rest) = take_any(rest, &["هفته", "ماه", "سال"])?;
+    let (direction, rest) = match this {
+        true => (0, rest),
+        false => take_direction(rest)?,
+    };
+    let part = match unit {
+        0 => add_days(today, -(today.day_of_week() as i64) + 7 * direction)
+            .and_then(|start| Ok(DatePart::Days(start, add_days(&start, 7)?))),
+        1 => add_months(&NaiveJalaliDate { day: 1, ..*today }, direction)
+            .and_then(|start| Ok(DatePart::Days(start, add_months(&start, 1)?))),
+        _ => year_range(today.year as i64 + direction),
+    };
+    Some((part, rest))
+}
+
+fn year_range(year : i64) -> Result<DatePart, ParseError> {
+    let first_day = |year : i64| i32::try_from(year).ok().and_then(|year| NaiveJalaliDate::from_ymd_opt(year, 1, 1));
+    match (first_day(year), first_day(year + 1)) {
+        (Some(start), Some(end)) => Ok(DatePart::Days(start, end)),
+        _ => Err(ParseError::OutOfRange),
+    }
+}
+
+/// Week day with optional direction like `شنبه آینده`
+fn take_week_day<'s>(s : &'s str, today : &NaiveJalaliDate) -> Taken<'s> {
+    let s = take_word(s, "این").unwrap_or(s);
+    const WEEK_DAYS : [(&str, i64); 12] = [("شنبه", 0), ("یک شنبه", 1), ("یکشنبه", 1), ("دوشنبه", 2), ("دو شنبه", 2), ("سه شنبه", 3),
+        ("سشنبه", 3), ("چهارشنبه", 4), ("چهار شنبه", 4), ("پنج شنبه", 5), ("پنجشنبه", 5), ("جمعه", 6)];
+    let (index, rest) = take_any(s, &WEEK_DAYS.map(|(name, _)| name))?;
+    let day_of_week = WEEK_DAYS[index].1;
+    let (direction, rest) = take_direction(rest).unwrap_or((0, rest));
+    let distance = (day_of_week - today.day_of_week() as i64).rem_euclid(7);
+    let days = match direction {
+        0 => distance,
+        1 => if distance == 0 { 7 } else { distance },
+        _ => if distance == 0 { -7 } else { distance - 7 },
+    };
+    Some((add_days(today, days).map(DatePart::Day), rest))
+}
+
+/// Distance like `سه روز بعد` or `۲ ساعت پیش`
+fn take_distance<'s>(s : &'s str, today : &NaiveJalaliDate) -> Taken<'s> {
+    let (count, rest) = take_count(s)?;
+    let (unit, rest) = take_any(rest, &["دقیقه", "ساعت", "روز", "هفته", "ماه", "سال"])?;
+    let (direction, rest) = take_direction(rest)?;
+    let count = count.checked_mul(direction).ok_or(ParseError::OutOfRange);
+    let part = match unit {
+        0 => count.and_then(|count| Duration::try_minutes(count).ok_or(ParseError::OutOfRange)).map(DatePart::Offset),
+        1 => count.and_then(|count| Duration::try_hours(count).ok_or(ParseError::OutOfRange)).map(DatePart::Offset),
+        2 => count.and_then(|count| add_days(today, count)).map(DatePart::Day),
+        3 => count.and_then(|count| add_days(today, count.checked_mul(7).ok_or(ParseError::OutOfRange)?)).map(DatePart::Day),
+        4 => count.and_then(|count| add_months(today, count)).map(DatePart::Day),
+        _ => count.and_then(|count| add_months(today, count.checked_mul(12).ok_or(ParseError::OutOfRange)?)).map(DatePart::Day),
+    };
+    Some((part, rest))
+}
+
+/// Day of month like `۱۵ فروردین ۱۴۰۳`، `اول اردیبهشت` or `1403/01/15`
+fn take_day_of_month<'s>(s : &'s str, today : &NaiveJalaliDate) -> Taken<'s> {
+    if let Some(result) = take_numeric_date(s) {
+        return Some(result);
+    }
+    let (day, rest) = take_count(s)?;
+    let months : [&str; 24] = core::array::from_fn(|index| match index {
+        0..=11 => Locale::Persian.month_name(index as u32 + 1),
+        _ => Locale::Dari.month_name(index as u32 - 11),
+    });
+    let (month, rest) = take_any(rest, &months)?;
+    let rest = take_word(rest, "ماه").unwrap_or(rest);
+    let (year, rest) = match take_count(rest) {
+        Some((year, rest)) => (i32::try_from(year).ok()?, rest),
+        None => (today.year, rest),
+    };
+    let date = NaiveJalaliDate::from_ymd_opt(year, month as u32 % 12 + 1, u32::try_from(day).ok()?)?;
+    Some((Ok(DatePart::Day(date)), rest))
+}
+
+fn take_numeric_date(s : &str) -> Taken<'_> {
+    let mut fields = [0u32; 3];
+    let mut rest = s;
+    for (index, field) in fields.iter_mut().enumerate() {
+        if index > 0 {
+            rest = rest.strip_prefix(['/', '-'])?;
+        }
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        *field = rest[..digits].parse().ok()?;
+        rest = &rest[digits..];
+    }
+    let date = NaiveJalaliDate::from_ymd_opt(fields[0] as i32, fields[1], fields[2])?;
+    Some((Ok(DatePart::Day(date)), rest.trim_start()))
+}
+
+/// Time of day like `ساعت ۵ عصر`، `ساعت ۸ و نیم` or `صبح`
+fn take_time(s : &str) -> Option<((u32, u32), &str)> {
+    const PERIODS : [(&str, u32); 9] = [("صبح", 9), ("ظهر", 12), ("بعد از ظهر", 15), ("بعدازظهر", 15), ("عصر", 17),
+        ("شب", 21), ("نیمه شب", 0), ("am", 0), ("pm", 12)];
+    let periods = PERIODS.map(|(name, _)| name);
+    let (hour, minute, rest) = match take_word(s, "ساعت") {
+        Some(rest) => {
+            let (hour, rest) = take_count(rest)?;
+            let (minute, rest) = if let Some(rest) = rest.strip_prefix(':') {
+                let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+                (rest[..digits].parse().ok()?, rest[digits..].trim_start())
+            } else if let Some(rest) = take_word(rest, "و نیم") {
+                (30, rest)
+            } else if let Some(rest) = take_word(rest, "و ربع") {
+                (15, rest)
+            } else {
+                (0, rest)
+            };
+            (u32::try_from(hour).ok()?, minute, rest)
+        }
+        None => {
+            let (index, rest) = take_any(s, &periods)?;
+            return Some(((PERIODS[index].1, 0), rest));
+        }
+    };
+    let (hour, rest) = match take_any(rest, &periods) {
+        Some((index, rest)) => (period_hour(hour, PERIODS[index].0)?, rest),
+        None => (hour, rest),
+    };
+    if hour > 23 || minute > 59 {
+        return None;
+    }
+    Some(((hour, minute), rest))
+}
+
+/// Convert 12 hour clock to 24 hour clock by period of day, `۱۲ شب` is midnight and `۱ شب` is after midnight
+fn period_hour(hour : u32, period : &str) -> Option<u32> {
+    if hour > 12 {
+        return Some(hour);
+    }
+    Some(match period {
+        "صبح" | "am" => hour % 12,
+        "ظهر" => if hour <= 4 { hour + 12 } else { hour },
+        "شب" => if (6..12).contains(&hour) { hour + 12 } else { hour % 12 },
+        "نیمه شب" => hour % 12,
+        _ => hour % 12 + 12,
+    })
+}
+
+/// Add days, `OutOfRange` when result is out of supported range
+fn add_days(date : &NaiveJalaliDate, days : i64) -> Result<NaiveJalaliDate, ParseError> {
+    date.to_jdn().checked_add(days).and_then(NaiveJalaliDate::from_jdn_opt).ok_or(ParseError::OutOfRange)
+}
+
+/// Add months, day is clamped to last day of month
+fn add_months(date : &NaiveJalaliDate, months : i64) -> Result<NaiveJalaliDate, ParseError> {
+    let index = (date.year as i64 * 12 + date.month as i64 - 1).checked_add(months).ok_or(ParseError::OutOfRange)?;
+    let year = i32::try_from(index.div_euclid(12)).map_err(|_| ParseError::OutOfRange)?;
+    let month = index.rem_euclid(12) as u32 + 1;
+    if !(calendar::MIN_YEAR..=calendar::MAX_YEAR).contains(&year) {
+        return Err(ParseError::OutOfRange);
+    }
+    NaiveJalaliDate::from_ymd_opt(year, month, date.day.min(calendar::month_days(year, month))).ok_or(ParseError::OutOfRange)
+}
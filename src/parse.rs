@@ -0,0 +1,453 @@
+//! Parsing engine shared by strftime patterns ([strptime](crate::strptime)) and moment patterns ([moment](crate::moment))
+//!
+//! Input is matched against any iterator of format [Item]s, modifiers are accepted and ignored
+//! because numbers are read with variable width. Persian (۰-۹) and arabic (٠-٩) digits are accepted too
+
+use core::fmt::{Display, Formatter};
+use core::iter::Peekable;
+use chrono::{Datelike, NaiveDate};
+use chrono_tz::Tz;
+use crate::calendar;
+use crate::{era, words};
+use crate::format::{Directive, Item};
+use crate::structure::{NaiveJalaliDate, PDate, Reader, Setter};
+
+/// Error of parsing date from string
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ParseError {
+    /// Input does not match the pattern
+    Invalid,
+    /// Input ended before the pattern
+    TooShort,
+    /// Input has extra characters after the pattern
+    TooLong,
+    /// A field is out of its range or the date does not exist
+    OutOfRange,
+    /// Pattern has not enough fields to make a date
+    NotEnough,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            ParseError::Invalid => "input does not match the pattern",
+            ParseError::TooShort => "input is too short",
+            ParseError::TooLong => "input has trailing characters",
+            ParseError::OutOfRange => "date or time is out of range",
+            ParseError::NotEnough => "not enough fields to make a date",
+        })
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Fields collected from input
+#[derive(Default, Debug)]
+struct Parsed {
+    year : Option<i32>,
+    century : Option<i32>,
+    year_mod_100 : Option<i32>,
+    month : Option<u32>,
+    day : Option<u32>,
+    day_of_year : Option<u32>,
+    grg_year : Option<i32>,
+    grg_year_mod_100 : Option<i32>,
+    grg_month : Option<u32>,
+    grg_day : Option<u32>,
+    grg_day_of_year : Option<u32>,
+    hijri_year : Option<i32>,
+    hijri_month : Option<u32>,
+    hijri_day : Option<u32>,
+    fasli_month : Option<u32>,
+    fasli_day : Option<u32>,
+    hour : Option<u32>,
+    hour_12 : Option<u32>,
+    pm : Option<bool>,
+    minute : Option<u32>,
+    second : Option<u32>,
+    nano_second : Option<u32>,
+    offset : Option<i32>,
+    timestamp_millis : Option<i64>,
+}
+
+const MERIDIEMS : [(&str, bool); 8] = [
+    ("قبل از ظهر", false), ("بعد از ظهر", true), ("ق.ظ", false), ("ب.ظ", true),
+    ("AM", false), ("PM", true), ("A.M.", false), ("P.M.", true),
+];
+
+/// Parse input with pattern items, local times are in given timezone unless input has an offset
+///
+/// Works with any item source like [StrftimeItems](crate::format::StrftimeItems), [FormatItems](crate::format::FormatItems)
+/// or [MomentItems](crate::moment::MomentItems)
+pub fn parse<'a, I : Iterator<Item = Item<'a>>>(input : &str, items : I, time_zone : Tz) -> Result<PDate, ParseError> {
+    let parsed = parse_fields(input, items)?;
+    if let Some(millis) = parsed.timestamp_millis {
+        return from_millis(millis, time_zone);
+    }
+    let date = parsed.date()?;
+    let (hour, minute, second, nano_second) = parsed.time()?;
+    let millis = nano_second as i64 / 1_000_000;
+    let mut pdate = match parsed.offset {
+        Some(offset) => {
+            let naive = date.to_gregorian().and_hms_opt(hour, minute, second).ok_or(ParseError::OutOfRange)?;
+            from_millis(naive.and_utc().timestamp_millis() - offset as i64 * 1000, time_zone)?
+        }
+        None => PDate::from_naive_jalali(date, hour, minute, second, time_zone),
+    };
+    if millis > 0 {
+        pdate.set_time_millis(pdate.time_millis() + millis).map_err(|_| ParseError::OutOfRange)?;
+    }
+    Ok(pdate)
+}
+
+/// Make date from timestamp, `OutOfRange` when it is out of supported range
+fn from_millis(millis : i64, time_zone : Tz) -> Result<PDate, ParseError> {
+    let mut pdate = PDate { time_zone, ..PDate::default() };
+    pdate.set_time_millis(millis).map_err(|_| ParseError::OutOfRange)?;
+    Ok(pdate)
+}
+
+/// Parse only the date part of input with pattern items
+pub fn parse_date<'a, I : Iterator<Item = Item<'a>>>(input : &str, items : I) -> Result<NaiveJalaliDate, ParseError> {
+    parse_fields(input, items)?.date()
+}
+
+fn parse_fields<'a, I : Iterator<Item = Item<'a>>>(input : &str, items : I) -> Result<Parsed, ParseError> {
+    let mut parsed = Parsed::default();
+    let mut rest = input;
+    let mut items : Peekable<I> = items.peekable();
+    while let Some(item) = items.next() {
+        match item {
+            Item::Literal(literal) => rest = take_literal(rest, literal)?,
+            Item::Directive(directive, _) => {
+                // years have no fixed width, limit them when another field follows without separator
+                let year_width = if matches!(items.peek(), Some(Item::Directive(..))) { 4 } else { 10 };
+                rest = parsed.take_directive(rest, directive, year_width)?;
+            }
+        }
+    }
+    if !rest.is_empty() {
+        return Err(ParseError::TooLong);
+    }
+    Ok(parsed)
+}
+
+impl Parsed {
+    fn take_directive<'s>(&mut self, s : &'s str, directive : Directive, year_width : usize) -> Result<&'s str, ParseError> {
+        let s = match directive {
+            Directive::Year => take_signed(s, year_width, &mut self.year)?,
+            Directive::Century => take_signed(s, 2, &mut self.century)?,
+            Directive::YearMod100 => take_signed(s, 2, &mut self.year_mod_100)?,
+            Directive::Month => take_unsigned(s, 2, &mut self.month)?,
+            Directive::MonthName => {
+                let (index, s) = take_name(s, &crate::locale::PERSIAN_MONTH_NAMES)?;
+                self.month = Some(index as u32 + 1);
+                s
+            }
+            Directive::Day | Directive::DaySpacePadded => take_unsigned(s, 2, &mut self.day)?,
+            Directive::DayName => take_name(s, &crate::locale::PERSIAN_DAY_NAMES)?.1,
+            Directive::ShortDayName => take_name(s, &crate::locale::PERSIAN_SHORT_DAY_NAMES)?.1,
+            Directive::DayOfWeek | Directive::GrgDayOfWeek => take_unsigned(s, 1, &mut None)?,
+            Directive::WeekOfYear => take_unsigned(s, 2, &mut None)?,
+            Directive::DayOfYear => take_unsigned(s, 3, &mut self.day_of_year)?,
+            Directive::Hour | Directive::HourSpacePadded => take_unsigned(s, 2, &mut self.hour)?,
+            Directive::Hour12 | Directive::Hour12SpacePadded => take_unsigned(s, 2, &mut self.hour_12)?,
+            Directive::TimeOfDay | Directive::ShortTimeOfDay => {
+                let names = MERIDIEMS.map(|(name, _)| name);
+                let (index, s) = take_name(s, &names)?;
+                self.pm = Some(MERIDIEMS[index].1);
+                s
+            }
+            Directive::Minute => take_unsigned(s, 2, &mut self.minute)?,
+            Directive::Second => take_unsigned(s, 2, &mut self.second)?,
+            Directive::Nanosecond => take_fraction(s, &mut self.nano_second)?,
+            Directive::Millisecond => {
+                let s = take_unsigned(s, 3, &mut self.nano_second)?;
+                self.nano_second = self.nano_second.map(|millis| millis * 1_000_000);
+                s
+            }
+            // whole seconds are written without fraction
+            Directive::SecondFraction => match s.strip_prefix('.') {
+                Some(s) => take_fraction(s, &mut self.nano_second)?,
+                None => s,
+            },
+            Directive::TimeZone => take_offset(s, true, &mut self.offset)?,
+            Directive::TimeZoneCompact => take_offset(s, false, &mut self.offset)?,
+            Directive::Timestamp => {
+                let (value, s) = take_number(s, 20, true)?;
+                self.timestamp_millis = Some(value.checked_mul(1000).ok_or(ParseError::OutOfRange)?);
+                s
+            }
+            Directive::TimestampMillis => {
+                let (value, s) = take_number(s, 20, true)?;
+                self.timestamp_millis = Some(value);
+                s
+            }
+            Directive::GrgYear => take_signed(s, year_width, &mut self.grg_year)?,
+            Directive::GrgYearMod100 => take_signed(s, 2, &mut self.grg_year_mod_100)?,
+            Directive::GrgMonth => take_unsigned(s, 2, &mut self.grg_month)?,
+            Directive::GrgMonthName => {
+                let (index, s) = take_name(s, &crate::locale::ENGLISH_GRG_MONTH_NAMES)?;
+                self.grg_month = Some(index as u32 + 1);
+                s
+            }
+            Directive::GrgShortMonthName => {
+                let names = crate::locale::ENGLISH_GRG_MONTH_NAMES.map(|name| &name[..3]);
+                let (index, s) = take_name(s, &names)?;
+                self.grg_month = Some(index as u32 + 1);
+                s
+            }
+            Directive::GrgDay | Directive::GrgDaySpacePadded => take_unsigned(s, 2, &mut self.grg_day)?,
+            Directive::GrgDayName => take_name(s, &crate::locale::ENGLISH_DAY_NAMES)?.1,
+            Directive::GrgDayOfYear => take_unsigned(s, 3, &mut self.grg_day_of_year)?,
+            Directive::HijriYear => take_signed(s, year_width, &mut self.hijri_year)?,
+            Directive::HijriMonth => take_unsigned(s, 2, &mut self.hijri_month)?,
+            Directive::HijriMonthName => {
+                let (index, s) = take_name(s, &crate::locale::HIJRI_MONTH_NAMES)?;
+                self.hijri_month = Some(index as u32 + 1);
+                s
+            }
+            Directive::HijriDay | Directive::HijriDaySpacePadded => take_unsigned(s, 2, &mut self.hijri_day)?,
+            Directive::EraYear(era) => {
+                let (year, s) = take_number(s, year_width, true)?;
+                let year = year - era.from_jalali_year(0) as i64;
+                self.year = Some(i32::try_from(year).map_err(|_| ParseError::OutOfRange)?);
+                s
+            }
+            Directive::FasliMonth => take_unsigned(s, 2, &mut self.fasli_month)?,
+            Directive::FasliMonthName => match s.strip_prefix(era::GATHA_NAME) {
+                Some(s) => {
+                    self.fasli_month = Some(13);
+                    s
+                }
+                None => {
+                    let (index, s) = take_name(s, &crate::locale::PERSIAN_MONTH_NAMES)?;
+                    self.fasli_month = Some(index as u32 + 1);
+                    s
+                }
+            },
+            Directive::FasliDay => take_unsigned(s, 2, &mut self.fasli_day)?,
+            Directive::FasliDayName => take_name(s, &era::FASLI_DAY_NAMES).or_else(|_| take_name(s, &era::GATHA_DAY_NAMES))?.1,
+            Directive::YearInWords => {
+                let (year, s) = words::take_number(s).ok_or(ParseError::Invalid)?;
+                self.year = Some(i32::try_from(year).map_err(|_| ParseError::OutOfRange)?);
+                s
+            }
+            Directive::DayOrdinal => {
+                let (day, s) = words::take_number(s).ok_or(ParseError::Invalid)?;
+                self.day = Some(u32::try_from(day).map_err(|_| ParseError::OutOfRange)?);
+                s
+            }
+            Directive::ShortMonthName => {
+                let (index, s) = take_name(s, &crate::locale::PERSIAN_MONTH_NAMES)?;
+                self.month = Some(index as u32 + 1);
+                s
+            }
+            Directive::MinDayName => take_name(s, &crate::locale::PERSIAN_SHORT_DAY_NAMES)?.1,
+            Directive::GrgDayOrdinal => {
+                let (day, s) = words::take_number(s).ok_or(ParseError::Invalid)?;
+                self.grg_day = Some(u32::try_from(day).map_err(|_| ParseError::OutOfRange)?);
+                s
+            }
+            Directive::MonthNameWithMah => {
+                let (index, s) = take_name(s, &crate::locale::PERSIAN_MONTH_NAMES)?;
+                self.month = Some(index as u32 + 1);
+                let s = s.trim_start_matches([' ', '\u{200c}']);
+                s.strip_prefix("ماه").ok_or(ParseError::Invalid)?
+            }
+        };
+        Ok(s)
+    }
+
+    fn date(&self) -> Result<NaiveJalaliDate, ParseError> {
+        let year = match (self.year, self.century, self.year_mod_100) {
+            (Some(year), _, _) => Some(year),
+            (None, Some(century), Some(year)) => Some(century * 100 + year),
+            (None, None, Some(year)) => Some(if year >= 50 { 1300 + year } else { 1400 + year }),
+            _ => None,
+        };
+        let grg_year = match (self.grg_year, self.grg_year_mod_100) {
+            (Some(year), _) => Some(year),
+            (None, Some(year)) => Some(if year >= 70 { 1900 + year } else { 2000 + year }),
+            _ => None,
+        };
+        if let (Some(year), Some(month), Some(day)) = (year, self.month, self.day) {
+            return NaiveJalaliDate::from_ymd_opt(year, month, day).ok_or(ParseError::OutOfRange);
+        }
+        if let (Some(year), Some(month), Some(day)) = (year, self.fasli_month, self.fasli_day) {
+            if !(1..=13).contains(&month) || !(1..=30).contains(&day) || (month == 13 && day > 6) {
+                return Err(ParseError::OutOfRange);
+            }
+            let (year, month, day) = calendar::fasli_to_jalali(year, month, day);
+            return NaiveJalaliDate::from_ymd_opt(year, month, day).ok_or(ParseError::OutOfRange);
+        }
+        if let (Some(year), Some(day_of_year)) = (year, self.day_of_year) {
+            let (month, day) = match day_of_year {
+                1..=186 => ((day_of_year - 1) / 31 + 1, (day_of_year - 1) % 31 + 1),
+                187..=366 => ((day_of_year - 187) / 30 + 7, (day_of_year - 187) % 30 + 1),
+                _ => return Err(ParseError::OutOfRange),
+            };
+            return NaiveJalaliDate::from_ymd_opt(year, month, day).ok_or(ParseError::OutOfRange);
+        }
+        let grg_date = match (grg_year, self.grg_month, self.grg_day, self.grg_day_of_year) {
+            (Some(year), Some(month), Some(day), _) => Some(NaiveDate::from_ymd_opt(year, month, day)),
+            (Some(year), _, _, Some(day_of_year)) => Some(NaiveDate::from_yo_opt(year, day_of_year)),
+            _ => None,
+        };
+        if let Some(date) = grg_date {
+            let date = date.ok_or(ParseError::OutOfRange)?;
+            calendar::checked_gregorian_to_jalali(date.year(), date.month(), date.day()).ok_or(ParseError::OutOfRange)?;
+            return Ok(NaiveJalaliDate::from_gregorian(date));
+        }
+        if let (Some(year), Some(month), Some(day)) = (self.hijri_year, self.hijri_month, self.hijri_day) {
+            if !(calendar::MIN_HIJRI_YEAR..=calendar::MAX_HIJRI_YEAR).contains(&year) || !(1..=12).contains(&month)
+                || day < 1 || day > calendar::hijri_month_days(year, month) {
+                return Err(ParseError::OutOfRange);
+            }
+            let (year, month, day) = calendar::hijri_to_jalali(year, month, day);
+            return NaiveJalaliDate::from_ymd_opt(year, month, day).ok_or(ParseError::OutOfRange);
+        }
+        Err(ParseError::NotEnough)
+    }
+
+    fn time(&self) -> Result<(u32, u32, u32, u32), ParseError> {
+        let hour = match (self.hour_12, self.pm) {
+            (Some(hour), pm) => {
+                if !(1..=12).contains(&hour) {
+                    return Err(ParseError::OutOfRange);
+                }
+                hour % 12 + if pm == Some(true) { 12 } else { 0 }
+            }
+            (None, Some(true)) => self.hour.map_or(12, |hour| if hour < 12 { hour + 12 } else { hour }),
+            (None, _) => self.hour.unwrap_or(0),
+        };
+        let minute = self.minute.unwrap_or(0);
+        let second = self.second.unwrap_or(0);
+        if hour > 23 || minute > 59 || second > 59 {
+            return Err(ParseError::OutOfRange);
+        }
+        Ok((hour, minute, second, self.nano_second.unwrap_or(0)))
+    }
+}
+
+/// Match literal text, whitespace in pattern matches any amount of whitespace
+fn take_literal<'s>(mut s : &'s str, literal : &str) -> Result<&'s str, ParseError> {
+    for c in literal.chars() {
+        if c.is_whitespace() {
+            s = s.trim_start();
+            continue;
+        }
+        s = match s.strip_prefix(c) {
+            Some(rest) => rest,
+            None if s.is_empty() => return Err(ParseError::TooShort),
+            None => return Err(ParseError::Invalid),
+        };
+    }
+    Ok(s)
+}
+
+/// Return value of ascii, persian or arabic digit
+fn digit_value(c : char) -> Option<u32> {
+    match c {
+        '0'..='9' => Some(c as u32 - '0' as u32),
+        '۰'..='۹' => Some(c as u32 - '۰' as u32),
+        '٠'..='٩' => Some(c as u32 - '٠' as u32),
+        _ => None,
+    }
+}
+
+/// Read a number of at most `max_width` digits, leading spaces are skipped
+fn take_number(s : &str, max_width : usize, signed : bool) -> Result<(i64, &str), ParseError> {
+    let mut s = s.trim_start_matches(' ');
+    let mut negative = false;
+    if signed {
+        if let Some(rest) = s.strip_prefix('-') {
+            negative = true;
+            s = rest;
+        } else if let Some(rest) = s.strip_prefix('+') {
+            s = rest;
+        }
+    }
+    let mut value : i64 = 0;
+    let mut width = 0;
+    let mut end = 0;
+    for (index, c) in s.char_indices() {
+        if width == max_width {
+            break;
+        }
+        let Some(digit) = digit_value(c) else {
+            break;
+        };
+        value = value.checked_mul(10).and_then(|value| value.checked_add(digit as i64)).ok_or(ParseError::OutOfRange)?;
+        width += 1;
+        end = index + c.len_utf8();
+    }
+    if width == 0 {
+        return Err(if s.is_empty() { ParseError::TooShort } else { ParseError::Invalid });
+    }
+    Ok((if negative { -value } else { value }, &s[end..]))
+}
+
+fn take_signed<'s>(s : &'s str, max_width : usize, field : &mut Option<i32>) -> Result<&'s str, ParseError> {
+    let (value, s) = take_number(s, max_width, true)?;
+    *field = Some(i32::try_from(value).map_err(|_| ParseError::OutOfRange)?);
+    Ok(s)
+}
+
+fn take_unsigned<'s>(s : &'s str, max_width : usize, field : &mut Option<u32>) -> Result<&'s str, ParseError> {
+    let (value, s) = take_number(s, max_width, false)?;
+    *field = Some(u32::try_from(value).map_err(|_| ParseError::OutOfRange)?);
+    Ok(s)
+}
+
+/// Read up to 9 digits of fraction of second as nanoseconds, fractions are not padded
+fn take_fraction<'s>(s : &'s str, field : &mut Option<u32>) -> Result<&'s str, ParseError> {
+    if s.starts_with(' ') {
+        return Err(ParseError::Invalid);
+    }
+    let (value, rest) = take_number(s, 9, false)?;
+    let width = s[..s.len() - rest.len()].chars().count() as u32;
+    *field = Some(value as u32 * 10u32.pow(9 - width));
+    Ok(rest)
+}
+
+/// Read timezone offset like `+03:30`, `+0330` or `Z`
+fn take_offset<'s>(s : &'s str, colon : bool, field : &mut Option<i32>) -> Result<&'s str, ParseError> {
+    if let Some(rest) = s.strip_prefix('Z') {
+        *field = Some(0);
+        return Ok(rest);
+    }
+    let (sign, s) = match s.chars().next() {
+        Some('+') => (1, &s[1..]),
+        Some('-') => (-1, &s[1..]),
+        Some(_) => return Err(ParseError::Invalid),
+        None => return Err(ParseError::TooShort),
+    };
+    let mut hours = None;
+    let mut minutes = None;
+    let s = take_unsigned(s, 2, &mut hours)?;
+    let s = if colon { take_literal(s, ":")? } else { s };
+    let s = take_unsigned(s, 2, &mut minutes)?;
+    let (hours, minutes) = (hours.unwrap_or(0) as i32, minutes.unwrap_or(0) as i32);
+    if hours > 23 || minutes > 59 {
+        return Err(ParseError::OutOfRange);
+    }
+    *field = Some(sign * (hours * 3600 + minutes * 60));
+    Ok(s)
+}
+
+/// Match the longest name from list (ascii case-insensitive), return its index
+fn take_name<'s>(s : &'s str, names : &[&str]) -> Result<(usize, &'s str), ParseError> {
+    let mut found : Option<(usize, usize)> = None;
+    for (index, name) in names.iter().enumerate() {
+        let matched = s.get(..name.len()).is_some_and(|prefix| prefix.eq_ignore_ascii_case(name));
+        if matched && found.is_none_or(|(_, len)| name.len() > len) {
+            found = Some((index, name.len()));
+        }
+    }
+    match found {
+        Some((index, len)) => Ok((index, &s[len..])),
+        None if s.is_empty() => Err(ParseError::TooShort),
+        None => Err(ParseError::Invalid),
+    }
+}
@@ -0,0 +1,173 @@
+//! Regional calendar profiles
+//!
+//! Iran and Afghanistan use the same solar hijri arithmetic, but differ in month names,
+//! time zone, weekend and holidays. A [CalendarProfile] switches all of them together
+//!
+//! Lunar holidays are computed with the tabular islamic calendar,
+//! which may differ one or two days from the officially observed calendar
+//!
+//! # Example
+//!
+//! ```
+//! use persian_date::jdate;
+//! use persian_date::profile::CalendarProfile;
+//!
+//! let afghan = CalendarProfile::Afghan;
+//! assert_eq!(afghan.month_name(1), "حمل");
+//! assert_eq!(afghan.holiday(&jdate!(1403-05-28)), Some("روز استقلال"));
+//! assert!(afghan.is_weekend(&jdate!(1403-01-16)));
+//! assert!(!CalendarProfile::Iranian.is_weekend(&jdate!(1403-01-16)));
+//! assert_eq!(CalendarProfile::Iranian.holiday(&jdate!(1403-06-14)), Some("شهادت امام رضا"));
+//! assert_eq!(afghan.week_start(), 0);
+//! ```
+
+#[cfg(feature = "tz")]
+use chrono_tz::Tz;
+use crate::calendar;
+use crate::locale::Locale;
+use crate::structure::NaiveJalaliDate;
+#[cfg(feature = "tz")]
+use crate::format::{DelayedFormat, StrftimeItems};
+#[cfg(feature = "tz")]
+use crate::structure::{PDate, Setter};
+
+/// Regional variant of the solar hijri calendar
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum CalendarProfile {
+    /// Iran : persian names, Tehran time, friday weekend
+    #[default]
+    Iranian,
+    /// Afghanistan : zodiac month names, Kabul time, thursday and friday weekend
+    Afghan,
+}
+
+/// Fixed holiday as `(month, day, name)`
+type Holiday = (u32, u32, &'static str);
+
+const IRANIAN_SOLAR_HOLIDAYS : [Holiday; 10] = [
+    (1, 1, "عید نوروز"), (1, 2, "عید نوروز"), (1, 3, "عید نوروز"), (1, 4, "عید نوروز"),
+    (1, 12, "روز جمهوری اسلامی"), (1, 13, "روز طبیعت"),
+    (3, 14, "رحلت امام خمینی"), (3, 15, "قیام ۱۵ خرداد"),
+    (11, 22, "پیروزی انقلاب اسلامی"), (12, 29, "روز ملی شدن صنعت نفت"),
+];
+
+// martyrdom of imam reza is on the last day of safar, which always has 29 days in tabular calendar
+const IRANIAN_LUNAR_HOLIDAYS : [Holiday; 17] = [
+    (1, 9, "تاسوعا"), (1, 10, "عاشورا"),
+    (2, 20, "اربعین حسینی"), (2, 28, "رحلت رسول اکرم و شهادت امام حسن مجتبی"), (2, 29, "شهادت امام رضا"),
+    (3, 8, "شهادت امام حسن عسکری"), (3, 17, "ولادت رسول اکرم و امام جعفر صادق"), (6, 3, "شهادت حضرت فاطمه زهرا"),
+    (7, 13, "ولادت امام علی"), (7, 27, "مبعث رسول اکرم"), (8, 15, "ولادت حضرت قائم"),
+    (9, 21, "شهادت امام علی"), (10, 1, "عید فطر"), (10, 2, "تعطیل به مناسبت عید فطر"),
+    (10, 25, "شهادت امام جعفر صادق"), (12, 10, "عید قربان"), (12, 18, "عید غدیر خم"),
+];
+
+const AFGHAN_SOLAR_HOLIDAYS : [Holiday; 3] = [
+    (1, 1, "نوروز"), (2, 8, "روز پیروزی جهاد"), (5, 28, "روز استقلال"),
+];
+
+const AFGHAN_LUNAR_HOLIDAYS : [Holiday; 10] = [
+    (1, 10, "عاشورا"), (3, 12, "میلاد پیامبر"), (9, 1, "اول رمضان"),
+    (10, 1, "عید فطر"), (10, 2, "عید فطر"), (10, 3, "عید فطر"),
+    (12, 9, "روز عرفه"), (12, 10, "عید قربان"), (12, 11, "عید قربان"), (12, 12, "عید قربان"),
+];
+
+impl CalendarProfile {
+    /// Return locale of month and day names
+    pub fn locale(&self) -> Locale {
+        match self {
+            CalendarProfile::Iranian => Locale::Persian,
+            CalendarProfile::Afghan => Locale::Dari,
+        }
+    }
+
+    /// Return default time zone
+    #[cfg(feature = "tz")]
+    pub fn time_zone(&self) -> Tz {
+        match self {
+            CalendarProfile::Iranian => Tz::Asia__Tehran,
+            CalendarProfile::Afghan => Tz::Asia__Kabul,
+        }
+    }
+
+    /// Return first day of week (saturday is 0)
+    ///
+    /// Weeks start on saturday in both countries (like `firstDay` of IR and AF in CLDR),
+    /// only their weekends differ
+    pub fn week_start(&self) -> u32 {
+        match self {
+            CalendarProfile::Iranian => 0,
+            CalendarProfile::Afghan => 0,
+        }
+    }
+
+    /// Return weekend days (saturday is 0)
+    pub fn weekend(&self) -> &'static [u32] {
+        match self {
+            CalendarProfile::Iranian => &[6],
+            CalendarProfile::Afghan => &[5, 6],
+        }
+    }
+
+    /// Return name of jalali month (1 to 12), panics like [Locale::month_name]
+    pub fn month_name(&self, month : u32) -> &'static str {
+        self.locale().month_name(month)
+    }
+
+    /// Return name of week day (saturday is 0), panics like [Locale::day_name]
+    pub fn day_name(&self, day_of_week : u32) -> &'static str {
+        self.locale().day_name(day_of_week)
+    }
+
+    /// Return date is in weekend or not
+    pub fn is_weekend(&self, date : &NaiveJalaliDate) -> bool {
+        self.weekend().contains(&date.day_of_week())
+    }
+
+    /// Return name of official holiday on date, weekends are not included
+    pub fn holiday(&self, date : &NaiveJalaliDate) -> Option<&'static str> {
+        let (solar, lunar) : (&[Holiday], &[Holiday]) = match self {
+            CalendarProfile::Iranian => (&IRANIAN_SOLAR_HOLIDAYS, &IRANIAN_LUNAR_HOLIDAYS),
+            CalendarProfile::Afghan => (&AFGHAN_SOLAR_HOLIDAYS, &AFGHAN_LUNAR_HOLIDAYS),
+        };
+        let (_, hm, hd) = calendar::jalali_to_hijri(date.year, date.month, date.day);
+        solar.iter().find(|(m, d, _)| *m == date.month && *d == date.day)
+            .or_else(|| lunar.iter().find(|(m, d, _)| *m == hm && *d == hd))
+            .map(|(_, _, name)| *name)
+    }
+
+    /// Return date is a day off (weekend or holiday) or not
+    pub fn is_day_off(&self, date : &NaiveJalaliDate) -> bool {
+        self.is_weekend(date) || self.holiday(date).is_some()
+    }
+
+    /// Return current date in time zone of profile
+    #[cfg(feature = "tz")]
+    pub fn now(&self) -> PDate {
+        let mut pdate = PDate::now();
+        pdate.set_time_zone(self.time_zone());
+        pdate
+    }
+
+    /// Make date from jalali date and time in time zone of profile
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use persian_date::jdate;
+    /// use persian_date::profile::CalendarProfile;
+    ///
+    /// let afghan = CalendarProfile::Afghan;
+    /// let pdate = afghan.from_naive_jalali(jdate!(1403-01-15), 9, 30, 0);
+    /// assert_eq!(afghan.format(&pdate, "%A %d %B %Y %H:%M %:z").to_string(), "چهارشنبه 15 حمل 1403 09:30 +04:30");
+    /// ```
+    #[cfg(feature = "tz")]
+    pub fn from_naive_jalali(&self, date : NaiveJalaliDate, hour : u32, minute : u32, second : u32) -> PDate {
+        PDate::from_naive_jalali(date, hour, minute, second, self.time_zone())
+    }
+
+    /// Format date with names of profile
+    #[cfg(feature = "tz")]
+    pub fn format<'a>(&self, date : &'a PDate, pattern : &'a str) -> DelayedFormat<'a, StrftimeItems<'a>> {
+        date.format_localized(pattern, self.locale())
+    }
+}
@@ -0,0 +1,552 @@
+//! Calendars rendered as plain text (like `cal` and `jcal`), HTML tables and SVG images
+//!
+//! # Example
+//!
+//! ```
+//! use persian_date::jdate;
+//! use persian_date::locale::{Digits, Locale};
+//! use persian_date::render::{GregorianDays, TextCalendar, TextCalendarOptions};
+//!
+//! let options = TextCalendarOptions { digits: Some(Digits::Latin), ..Default::default() };
+//! let text = TextCalendar::month(1403, 1, options).unwrap().to_string();
+//! assert_eq!(text, concat!(
+//!     "    فروردین 1403\n",
+//!     " ش  ی  د  س  چ  پ  ج\n",
+//!     "             1  2  3\n",
+//!     " 4  5  6  7  8  9 10\n",
+//!     "11 12 13 14 15 16 17\n",
+//!     "18 19 20 21 22 23 24\n",
+//!     "25 26 27 28 29 30 31\n",
+//! ));
+//!
+//! let options = TextCalendarOptions { locale: Locale::English, gregorian: GregorianDays::Below, today: Some(jdate!(1403-01-15)), ..Default::default() };
+//! let text = TextCalendar::month(1403, 1, options).unwrap().to_string();
+//! assert!(text.starts_with("   Farvardin 1403\nSa Su Mo Tu We Th Fr\n"));
+//! assert!(text.contains("\x1b[7m15\x1b[27m"));
+//!
+//! let options = TextCalendarOptions { digits: Some(Digits::Latin), gregorian: GregorianDays::Beside, holidays: true, ..Default::default() };
+//! let text = TextCalendar::month(1403, 1, options).unwrap().to_string();
+//! assert!(text.contains("11(30) \x1b[31m12\x1b[39m(31) \x1b[31m13\x1b[39m(1)  14(2)"));
+//! ```
+
+use core::fmt::{Display, Formatter, Write};
+use crate::grid::{self, DayCell, MonthGrid};
+use crate::locale::{Digits, DigitsWriter, Locale};
+use crate::profile::CalendarProfile;
+use crate::structure::NaiveJalaliDate;
+
+/// Separator of months side by side in a year
+const BLOCK_SEPARATOR : &str = "  ";
+/// Months in each row of a year
+const YEAR_COLUMNS : usize = 3;
+
+/// Direction of text calendar
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum TextDirection {
+    /// First day of week on the left, for terminals without bidi support
+    #[default]
+    Ltr,
+    /// Lines start with right-to-left mark, so bidi aware viewers (browsers, mail clients) put first day of week on the right
+    Rtl,
+}
+
+/// Gregorian days in text calendar
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum GregorianDays {
+    #[default]
+    None,
+    /// On a line under each week
+    Below,
+    /// Next to each jalali day in parentheses, like `15(3)`
+    Beside,
+}
+
+/// Options of [TextCalendar]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TextCalendarOptions {
+    /// Language of month and week day names
+    pub locale : Locale,
+    /// Digits of numbers, default is digits of locale
+    pub digits : Option<Digits>,
+    /// First day of week (saturday is 0)
+    pub week_start : u32,
+    pub direction : TextDirection,
+    /// Day highlighted with reverse video (ANSI escape codes)
+    pub today : Option<NaiveJalaliDate>,
+    pub gregorian : GregorianDays,
+    /// Weekends and holidays colored red (ANSI escape codes)
+    pub holidays : bool,
+}
+
+impl Default for TextCalendarOptions {
+    fn default() -> Self {
+        TextCalendarOptions { locale: Locale::Persian, digits: None, week_start: 0, direction: TextDirection::Ltr, today: None, gregorian: GregorianDays::None, holidays: false }
+    }
+}
+
+/// Plain text calendar of a month or a year, see [module](self)
+#[derive(Clone, Copy, Debug)]
+pub struct TextCalendar {
+    year : i32,
+    /// Single month, or the whole year on `None`
+    month : Option<u32>,
+    options : TextCalendarOptions,
+}
+
+impl TextCalendar {
+    /// Make calendar of a jalali month, return `None` on invalid month
+    pub fn month(year : i32, month : u32, options : TextCalendarOptions) -> Option<Self> {
+        grid::month_grid(year, month, options.week_start)?;
+        Some(TextCalendar { year, month: Some(month), options })
+    }
+
+    /// Make calendar of a jalali year, three months in each row
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use persian_date::render::{TextCalendar, TextCalendarOptions};
+    ///
+    /// let text = TextCalendar::year(1403, TextCalendarOptions::default()).unwrap().to_string();
+    /// assert!(text.starts_with("                              ۱۴۰۳\n"));
+    /// assert!(text.lines().nth(2).unwrap().starts_with("      فروردین               اردیبهشت"));
+    /// ```
+    pub fn year(year : i32, options : TextCalendarOptions) -> Option<Self> {
+        grid::month_grid(year, 12, options.week_start)?;
+        Some(TextCalendar { year, month: None, options })
+    }
+
+    fn lines_per_week(&self) -> usize {
+        if self.options.gregorian == GregorianDays::Below { 2 } else { 1 }
+    }
+
+    fn cell_width(&self) -> usize {
+        if self.options.gregorian == GregorianDays::Beside { 6 } else { 2 }
+    }
+
+    /// Return width of a month block, 7 cells and 6 separators
+    fn block_width(&self) -> usize {
+        self.cell_width() * 7 + 6
+    }
+
+    /// Return count of lines in month block
+    fn line_count(&self, grid : &MonthGrid) -> usize {
+        2 + grid.rows().len() * self.lines_per_week()
+    }
+
+    fn start_line<W : Write + ?Sized>(&self, f : &mut DigitsWriter<W>) -> core::fmt::Result {
+        match self.options.direction {
+            TextDirection::Ltr => Ok(()),
+            TextDirection::Rtl => f.inner.write_char('\u{200f}'),
+        }
+    }
+
+    /// Write a line of month block, title, week days or a line of week
+    fn write_block_line<W : Write + ?Sized>(&self, f : &mut DigitsWriter<W>, grid : &MonthGrid, line : usize, with_year : bool, pad : bool) -> core::fmt::Result {
+        let padding = |f : &mut DigitsWriter<W>, count : usize| (0..count).try_for_each(|_| f.write_char(' '));
+        match line {
+            0 => {
+                let name = self.options.locale.month_name(grid.month());
+                let mut width = name.chars().count();
+                if with_year {
+                    width += 1 + count_digits(grid.year());
+                }
+                let left = self.block_width().saturating_sub(width) / 2;
+                padding(f, left)?;
+                f.write_str(name)?;
+                if with_year {
+                    write!(f," {}",grid.year())?;
+                }
+                if pad {
+                    padding(f, self.block_width().saturating_sub(left + width))?;
+                }
+                Ok(())
+            }
+            1 => {
+                for (column, day_of_week) in grid.weekdays().into_iter().enumerate() {
+                    let name = self.options.locale.day_name(day_of_week);
+                    let take = if self.options.locale == Locale::English { 2 } else { 1 };
+                    let short = name.char_indices().nth(take).map_or(name, |(index, _)| &name[..index]);
+                    if column > 0 {
+                        f.write_char(' ')?;
+                    }
+                    padding(f, self.cell_width() - short.chars().count())?;
+                    f.write_str(short)?;
+                }
+                Ok(())
+            }
+            _ => {
+                let index = line - 2;
+                let Some(week) = grid.rows().get(index / self.lines_per_week()) else {
+                    return if pad { padding(f, self.block_width()) } else { Ok(()) };
+                };
+                let gregorian = index % self.lines_per_week() == 1;
+                // trailing blank cells are not written on the last block of line
+                let last = if pad { 6 } else { week.iter().rposition(|cell| cell.in_month).unwrap_or(0) };
+                for (column, cell) in week.iter().enumerate().take(last + 1) {
+                    if column > 0 {
+                        f.write_char(' ')?;
+                    }
+                    if !cell.in_month {
+                        padding(f, self.cell_width())?;
+                        continue;
+                    }
+                    let day = if gregorian { cell.gregorian.2 } else { cell.date.day() };
+                    let today = !gregorian && self.options.today == Some(cell.date);
+                    let holiday = !gregorian && self.options.holidays && (cell.is_weekend || cell.holiday.is_some());
+                    if today {
+                        f.inner.write_str("\x1b[7m")?;
+                    }
+                    if holiday {
+                        f.inner.write_str("\x1b[31m")?;
+                    }
+                    write!(f,"{:>2}",day)?;
+                    if holiday {
+                        f.inner.write_str("\x1b[39m")?;
+                    }
+                    if today {
+                        f.inner.write_str("\x1b[27m")?;
+                    }
+                    if self.options.gregorian == GregorianDays::Beside {
+                        write!(f,"({})",cell.gregorian.2)?;
+                        if pad || column < last {
+                            padding(f, usize::from(cell.gregorian.2 < 10))?;
+                        }
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Display for TextCalendar {
+    fn fmt(&self, f : &mut Formatter<'_>) -> core::fmt::Result {
+        let mut f = DigitsWriter { inner: f, digits: self.options.digits.unwrap_or(self.options.locale.digits()) };
+        let week_start = self.options.week_start;
+        if let Some(month) = self.month {
+            let grid = grid::month_grid(self.year, month, week_start).ok_or(core::fmt::Error)?;
+            for line in 0..self.line_count(&grid) {
+                self.start_line(&mut f)?;
+                self.write_block_line(&mut f, &grid, line, true, false)?;
+                f.write_char('\n')?;
+            }
+            return Ok(());
+        }
+        let width = self.block_width() * YEAR_COLUMNS + BLOCK_SEPARATOR.len() * (YEAR_COLUMNS - 1);
+        self.start_line(&mut f)?;
+        for _ in 0..(width - count_digits(self.year)) / 2 {
+            f.write_char(' ')?;
+        }
+        writeln!(f,"{}",self.year)?;
+        for first in (1..=12).step_by(YEAR_COLUMNS) {
+            let grids = [0, 1, 2].map(|column| grid::month_grid(self.year, first + column, week_start));
+            f.write_char('\n')?;
+            for line in 0..2 + 6 * self.lines_per_week() {
+                self.start_line(&mut f)?;
+                // blocks are padded only when a later block has content on this line
+                let filled = |grid : &Option<MonthGrid>| grid.as_ref().is_some_and(|grid| line < self.line_count(grid));
+                for (column, grid) in grids.iter().enumerate() {
+                    if !grids[column..].iter().any(filled) {
+                        break;
+                    }
+                    let grid = grid.as_ref().ok_or(core::fmt::Error)?;
+                    if column > 0 {
+                        f.write_str(BLOCK_SEPARATOR)?;
+                    }
+                    self.write_block_line(&mut f, grid, line, false, grids[column + 1..].iter().any(filled))?;
+                }
+                f.write_char('\n')?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Secondary day number written under each jalali day
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SecondaryDay {
+    Gregorian,
+    /// Tabular islamic calendar
+    Hijri,
+}
+
+/// Options of [HtmlCalendar] and [SvgCalendar]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MarkupOptions {
+    /// Names, weekend and holidays
+    pub profile : CalendarProfile,
+    /// Digits of numbers, default is digits of profile locale
+    pub digits : Option<Digits>,
+    /// First day of week (saturday is 0)
+    pub week_start : u32,
+    /// Day marked as today, no day is marked on `None`
+    pub today : Option<NaiveJalaliDate>,
+    pub secondary : Option<SecondaryDay>,
+}
+
+impl Default for MarkupOptions {
+    fn default() -> Self {
+        MarkupOptions { profile: CalendarProfile::Iranian, digits: None, week_start: 0, today: None, secondary: None }
+    }
+}
+
+impl MarkupOptions {
+    fn digits(&self) -> Digits {
+        self.digits.unwrap_or(self.profile.locale().digits())
+    }
+
+    fn grid(&self, year : i32, month : u32) -> Result<MonthGrid, core::fmt::Error> {
+        let grid = self.profile.month_grid(year, month, self.week_start).ok_or(core::fmt::Error)?;
+        Ok(match self.today {
+            Some(today) => grid.with_today(today),
+            None => grid,
+        })
+    }
+
+    /// Return secondary day number of cell
+    fn secondary_day(&self, cell : &DayCell) -> Option<u32> {
+        match self.secondary? {
+            SecondaryDay::Gregorian => Some(cell.gregorian.2),
+            SecondaryDay::Hijri => Some(cell.hijri.2),
+        }
+    }
+
+    /// Write classes of day cell, like `day weekend holiday`
+    fn write_classes(&self, f : &mut Formatter<'_>, cell : &DayCell) -> core::fmt::Result {
+        f.write_str("day")?;
+        let flags = [(!cell.in_month, " adjacent"), (cell.is_weekend, " weekend"), (cell.holiday.is_some(), " holiday"), (cell.is_today, " today")];
+        flags.into_iter().filter(|(flag, _)| *flag).try_for_each(|(_, class)| f.write_str(class))
+    }
+
+    /// Write text with digits of options
+    fn write_text(&self, f : &mut Formatter<'_>, args : core::fmt::Arguments<'_>) -> core::fmt::Result {
+        DigitsWriter { inner: f, digits: self.digits() }.write_fmt(args)
+    }
+}
+
+/// HTML table calendar of a month or a year, in right-to-left layout
+///
+/// Day cells have `day` class, with `adjacent` (day of previous or next month), `weekend`, `holiday` and `today` classes.
+/// Holiday names are written in `title` attribute
+///
+/// # Example
+///
+/// ```
+/// use persian_date::jdate;
+/// use persian_date::render::{HtmlCalendar, MarkupOptions, SecondaryDay};
+///
+/// let options = MarkupOptions { today: Some(jdate!(1403-01-15)), secondary: Some(SecondaryDay::Gregorian), ..Default::default() };
+/// let html = HtmlCalendar::month(1403, 1, options).unwrap().to_string();
+/// assert!(html.starts_with("<table class=\"pdate-month\" dir=\"rtl\">\n<caption>فروردین ۱۴۰۳</caption>\n<thead><tr><th>شنبه</th>"));
+/// assert!(html.contains("<td class=\"day holiday\" title=\"روز طبیعت\"><span class=\"jalali\">۱۳</span><span class=\"secondary\">۱</span></td>"));
+/// assert!(html.contains("<td class=\"day today\"><span class=\"jalali\">۱۵</span><span class=\"secondary\">۳</span></td>"));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct HtmlCalendar {
+    year : i32,
+    /// Single month, or the whole year on `None`
+    month : Option<u32>,
+    options : MarkupOptions,
+}
+
+impl HtmlCalendar {
+    /// Make calendar of a jalali month, return `None` on invalid month
+    pub fn month(year : i32, month : u32, options : MarkupOptions) -> Option<Self> {
+        NaiveJalaliDate::from_ymd_opt(year, month, 1)?;
+        Some(HtmlCalendar { year, month: Some(month), options })
+    }
+
+    /// Make calendar of a jalali year, a table for each month in a `div`
+    pub fn year(year : i32, options : MarkupOptions) -> Option<Self> {
+        NaiveJalaliDate::from_ymd_opt(year, 12, 1)?;
+        Some(HtmlCalendar { year, month: None, options })
+    }
+
+    fn write_month(&self, f : &mut Formatter<'_>, month : u32, with_year : bool) -> core::fmt::Result {
+        let options = &self.options;
+        let grid = options.grid(self.year, month)?;
+        let locale = options.profile.locale();
+        f.write_str(if with_year { "<table class=\"pdate-month\" dir=\"rtl\">\n<caption>" } else { "<table class=\"pdate-month\">\n<caption>" })?;
+        f.write_str(locale.month_name(month))?;
+        if with_year {
+            options.write_text(f, format_args!(" {}",self.year))?;
+        }
+        f.write_str("</caption>\n<thead><tr>")?;
+        for day_of_week in grid.weekdays() {
+            write!(f,"<th>{}</th>",locale.day_name(day_of_week))?;
+        }
+        f.write_str("</tr></thead>\n<tbody>\n")?;
+        for week in grid.rows() {
+            f.write_str("<tr>")?;
+            for cell in week {
+                f.write_str("<td class=\"")?;
+                options.write_classes(f, cell)?;
+                f.write_char('"')?;
+                if let Some(holiday) = cell.holiday {
+                    write!(f," title=\"{}\"",holiday)?;
+                }
+                options.write_text(f, format_args!("><span class=\"jalali\">{}</span>",cell.date.day()))?;
+                if let Some(day) = options.secondary_day(cell) {
+                    options.write_text(f, format_args!("<span class=\"secondary\">{}</span>",day))?;
+                }
+                f.write_str("</td>")?;
+            }
+            f.write_str("</tr>\n")?;
+        }
+        f.write_str("</tbody>\n</table>\n")
+    }
+}
+
+impl Display for HtmlCalendar {
+    fn fmt(&self, f : &mut Formatter<'_>) -> core::fmt::Result {
+        if let Some(month) = self.month {
+            return self.write_month(f, month, true);
+        }
+        f.write_str("<div class=\"pdate-year\" dir=\"rtl\">\n<h2>")?;
+        self.options.write_text(f, format_args!("{}",self.year))?;
+        f.write_str("</h2>\n")?;
+        (1..=12).try_for_each(|month| self.write_month(f, month, false))?;
+        f.write_str("</div>\n")
+    }
+}
+
+/// Size of a day cell in SVG
+const SVG_CELL : (u32, u32) = (40, 36);
+/// Height of month title and week day names in SVG
+const SVG_HEADER : (u32, u32) = (30, 24);
+/// Space around months in SVG
+const SVG_MARGIN : u32 = 20;
+/// Height of year title in SVG
+const SVG_YEAR_TITLE : u32 = 40;
+const SVG_STYLE : &str = "text{font-family:Vazirmatn,Tahoma,sans-serif;text-anchor:middle}\
+.year{font-size:22px;font-weight:bold}.title{font-size:16px;font-weight:bold}.weekday{font-size:12px;fill:#555}.secondary{font-size:9px;fill:#888}\
+.day rect{fill:#fff;stroke:#ddd}.day text{font-size:14px}.weekend text,.holiday text{fill:#c00}\
+.holiday rect{fill:#fee}.today rect{stroke:#06c;stroke-width:2}.adjacent text{fill:#bbb}";
+
+/// Standalone SVG calendar of a month or a year, in right-to-left layout
+///
+/// Days are `g` elements with the classes of [HtmlCalendar] and a holiday `title`, the default style can be overridden by CSS
+///
+/// # Example
+///
+/// ```
+/// use persian_date::render::{MarkupOptions, SvgCalendar};
+///
+/// let svg = SvgCalendar::month(1403, 1, MarkupOptions::default()).unwrap().to_string();
+/// assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"320\" height=\"274\" viewBox=\"0 0 320 274\" direction=\"rtl\">"));
+/// assert!(svg.contains("<g class=\"day holiday\"><title>روز طبیعت</title><rect x=\"180\" y=\"146\" width=\"40\" height=\"36\"/><text x=\"200\" y=\"169\">۱۳</text></g>"));
+/// assert!(svg.ends_with("</svg>\n"));
+///
+/// let svg = SvgCalendar::year(1403, MarkupOptions::default()).unwrap().to_string();
+/// assert_eq!(svg.matches("class=\"title\"").count(), 12);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct SvgCalendar {
+    year : i32,
+    /// Single month, or the whole year on `None`
+    month : Option<u32>,
+    options : MarkupOptions,
+}
+
+impl SvgCalendar {
+    /// Make calendar of a jalali month, return `None` on invalid month
+    pub fn month(year : i32, month : u32, options : MarkupOptions) -> Option<Self> {
+        NaiveJalaliDate::from_ymd_opt(year, month, 1)?;
+        Some(SvgCalendar { year, month: Some(month), options })
+    }
+
+    /// Make calendar of a jalali year, three months in each row
+    pub fn year(year : i32, options : MarkupOptions) -> Option<Self> {
+        NaiveJalaliDate::from_ymd_opt(year, 12, 1)?;
+        Some(SvgCalendar { year, month: None, options })
+    }
+
+    /// Return size of a month block with count of weeks
+    fn month_size(weeks : u32) -> (u32, u32) {
+        (SVG_CELL.0 * 7, SVG_HEADER.0 + SVG_HEADER.1 + SVG_CELL.1 * weeks)
+    }
+
+    /// Write a month block with top right corner at `(right, top)`
+    fn write_month(&self, f : &mut Formatter<'_>, month : u32, right : u32, top : u32, with_year : bool) -> core::fmt::Result {
+        let options = &self.options;
+        let grid = options.grid(self.year, month)?;
+        let locale = options.profile.locale();
+        let left = right - SVG_CELL.0 * 7;
+        write!(f,"<text class=\"title\" x=\"{}\" y=\"{}\">{}",left + SVG_CELL.0 * 7 / 2,top + 20,locale.month_name(month))?;
+        if with_year {
+            options.write_text(f, format_args!(" {}",self.year))?;
+        }
+        f.write_str("</text>\n")?;
+        // first day of week is the rightmost column
+        let x = |column : usize| right - SVG_CELL.0 * (column as u32 + 1);
+        for (column, day_of_week) in grid.weekdays().into_iter().enumerate() {
+            let name = locale.day_name(day_of_week);
+            let short = name.char_indices().nth(1).map_or(name, |(index, _)| &name[..index]);
+            writeln!(f,"<text class=\"weekday\" x=\"{}\" y=\"{}\">{}</text>",x(column) + SVG_CELL.0 / 2,top + SVG_HEADER.0 + 16,short)?;
+        }
+        for (row, week) in grid.rows().iter().enumerate() {
+            let y = top + SVG_HEADER.0 + SVG_HEADER.1 + SVG_CELL.1 * row as u32;
+            for (column, cell) in week.iter().enumerate() {
+                let x = x(column);
+                f.write_str("<g class=\"")?;
+                options.write_classes(f, cell)?;
+                f.write_str("\">")?;
+                if let Some(holiday) = cell.holiday {
+                    write!(f,"<title>{}</title>",holiday)?;
+                }
+                write!(f,"<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"/><text x=\"{}\" y=\"{}\">",x,y,SVG_CELL.0,SVG_CELL.1,x + SVG_CELL.0 / 2,y + 23)?;
+                options.write_text(f, format_args!("{}</text>",cell.date.day()))?;
+                if let Some(day) = options.secondary_day(cell) {
+                    write!(f,"<text class=\"secondary\" x=\"{}\" y=\"{}\">",x + SVG_CELL.0 / 2,y + 33)?;
+                    options.write_text(f, format_args!("{}</text>",day))?;
+                }
+                f.write_str("</g>\n")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for SvgCalendar {
+    fn fmt(&self, f : &mut Formatter<'_>) -> core::fmt::Result {
+        let (width, height) = match self.month {
+            Some(month) => {
+                let (width, height) = Self::month_size(self.options.grid(self.year, month)?.rows().len() as u32);
+                (width + SVG_MARGIN * 2, height + SVG_MARGIN * 2)
+            }
+            None => {
+                let (width, height) = Self::month_size(6);
+                (width * 3 + SVG_MARGIN * 4, SVG_YEAR_TITLE + height * 4 + SVG_MARGIN * 5)
+            }
+        };
+        writeln!(f,"<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\" direction=\"rtl\">")?;
+        writeln!(f,"<style>{}</style>",SVG_STYLE)?;
+        match self.month {
+            Some(month) => self.write_month(f, month, width - SVG_MARGIN, SVG_MARGIN, true)?,
+            None => {
+                write!(f,"<text class=\"year\" x=\"{}\" y=\"{}\">",width / 2,SVG_MARGIN + 24)?;
+                self.options.write_text(f, format_args!("{}</text>\n",self.year))?;
+                let (month_width, month_height) = Self::month_size(6);
+                for month in 1..=12 {
+                    // farvardin is on the top right
+                    let (row, column) = ((month - 1) / 3, (month - 1) % 3);
+                    let right = width - SVG_MARGIN - (month_width + SVG_MARGIN) * column;
+                    let top = SVG_YEAR_TITLE + SVG_MARGIN + (month_height + SVG_MARGIN) * row;
+                    self.write_month(f, month, right, top, false)?;
+                }
+            }
+        }
+        f.write_str("</svg>\n")
+    }
+}
+
+fn count_digits(number : i32) -> usize {
+    let mut count = if number < 0 { 2 } else { 1 };
+    let mut rest = number.unsigned_abs();
+    while rest >= 10 {
+        rest /= 10;
+        count += 1;
+    }
+    count
+}
@@ -0,0 +1,52 @@
+//! Parse dates from strings with strftime patterns, the counterpart of [Format](crate::format::Format)
+//!
+//! Patterns use the same directives as [Format](crate::format::Format), see [parse](crate::parse) for
+//! how fields are read. `%.f` accepts a missing fraction, as it is not written for whole seconds
+//!
+//! # Example
+//!
+//! ```
+//! use persian_date::structure::{NaiveJalaliDate, PDate, Reader};
+//!
+//! let pdate = PDate::parse_from_str("1403/01/15 09:30", "%Y/%m/%d %H:%M").unwrap();
+//! assert_eq!((pdate.grg_year(), pdate.grg_month(), pdate.grg_day(), pdate.hour()), (2024, 4, 3, 9));
+//!
+//! let date = NaiveJalaliDate::parse_from_str("۱۵ فروردین ۱۴۰۳", "%d %B %Y").unwrap();
+//! assert_eq!(date.to_string(), "1403-01-15");
+//!
+//! let date = NaiveJalaliDate::parse_from_str("2024-04-03", "%gY-%gm-%gd").unwrap();
+//! assert_eq!(date.to_string(), "1403-01-15");
+//!
+//! let date = NaiveJalaliDate::parse_from_str("2535/01/01", "%EI/%m/%d").unwrap();
+//! assert_eq!(date.to_string(), "1355-01-01");
+//!
+//! let date = NaiveJalaliDate::parse_from_str("پانزدهم فروردین ماه یک هزار و چهارصد و سه", "%Od %OB %OY").unwrap();
+//! assert_eq!(date.to_string(), "1403-01-15");
+//!
+//! let date = NaiveJalaliDate::parse_from_str("3762 گاتاها 6", "%EZ %EB %Ed").unwrap();
+//! assert_eq!(date.to_string(), "1403-12-30");
+//! ```
+
+use chrono_tz::Tz;
+use crate::format::StrftimeItems;
+use crate::parse::{self, ParseError};
+use crate::structure::{NaiveJalaliDate, PDate};
+
+impl PDate {
+    /// Parse date from string with format pattern, in default timezone (Iran)
+    pub fn parse_from_str(input : &str, pattern : &str) -> Result<PDate, ParseError> {
+        parse::parse(input, StrftimeItems::new(pattern), Tz::Iran)
+    }
+
+    /// Parse date from string with format pattern, local times are in given timezone
+    pub fn parse_from_str_tz(input : &str, pattern : &str, time_zone : Tz) -> Result<PDate, ParseError> {
+        parse::parse(input, StrftimeItems::new(pattern), time_zone)
+    }
+}
+
+impl NaiveJalaliDate {
+    /// Parse date from string with format pattern, time fields are ignored
+    pub fn parse_from_str(input : &str, pattern : &str) -> Result<NaiveJalaliDate, ParseError> {
+        parse::parse_date(input, StrftimeItems::new(pattern))
+    }
+}
@@ -1,6 +1,16 @@
+#[cfg(feature = "tz")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "tz")]
 use chrono_tz::Tz;
+#[cfg(feature = "tz")]
+use crate::calendar;
+#[cfg(feature = "tz")]
+use crate::era::{self, Era};
+#[cfg(feature = "tz")]
+use crate::locale::Locale;
 
 /// Persian Date Structure
+#[cfg(feature = "tz")]
 pub struct PDate {
     /// Jalali year
     pub(crate) year : i32,
@@ -28,7 +38,19 @@ pub struct PDate {
     pub(crate) time_zone : Tz
 }
 
+/// Jalali calendar date without time and timezone
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NaiveJalaliDate {
+    /// Jalali year
+    pub(crate) year : i32,
+    /// Jalali month
+    pub(crate) month : u32,
+    /// Jalali day
+    pub(crate) day : u32,
+}
+
 /// Reader Trait for fetching data from date
+#[cfg(feature = "tz")]
 pub trait Reader {
     /// Return the jalali year
     fn year(&self) -> i32;
@@ -47,7 +69,7 @@ pub trait Reader {
 
     /// Return hour in day
     fn hour(&self) -> u32;
-    /// Return hour in day with 12 time format
+    /// Return hour in day with 12 time format, midnight and noon are 12
     fn hour_12(&self) -> u32;
     /// Return minute in hour
     fn minute(&self) -> u32;
@@ -118,6 +140,98 @@ pub trait Reader {
     /// ```
     fn grg_month_name(&self) -> String;
 
+    /// Return name of week day in a locale
+    fn day_name_in(&self, locale : Locale) -> &'static str {
+        locale.day_name(self.day_of_week())
+    }
+
+    /// Return name of gregorian week day in a locale
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use persian_date::locale::Locale;
+    /// use persian_date::structure::{PDate,Reader};
+    ///
+    /// let pdate = PDate::from_time_millis(1712136600000);
+    /// assert_eq!(pdate.grg_day_name_in(Locale::Persian), "چهارشنبه");
+    /// assert_eq!(pdate.grg_month_name_in(Locale::Dari), "اپریل");
+    /// assert_eq!(pdate.month_name_in(Locale::Dari), "حمل");
+    /// ```
+    fn grg_day_name_in(&self, locale : Locale) -> &'static str {
+        locale.day_name(self.day_of_week())
+    }
+
+    /// Return name of month in a locale
+    fn month_name_in(&self, locale : Locale) -> &'static str {
+        locale.month_name(self.month())
+    }
+
+    /// Return name of gregorian month in a locale
+    fn grg_month_name_in(&self, locale : Locale) -> &'static str {
+        locale.grg_month_name(self.grg_month())
+    }
+
+    /// Return year in an era
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use persian_date::era::Era;
+    /// use persian_date::structure::{PDate,Reader};
+    ///
+    /// let pdate = PDate::from_time_millis(1712136600000);
+    /// assert_eq!(pdate.era_year(Era::Kurdish), 2724);
+    /// assert_eq!(pdate.era_year(Era::Imperial), 2583);
+    /// assert_eq!(pdate.fasli_day_name(), "دی‌به‌مهر");
+    /// ```
+    fn era_year(&self, era : Era) -> i32 {
+        era.from_jalali_year(self.year())
+    }
+
+    /// Return the zoroastrian fasli month (13 for gatha days)
+    fn fasli_month(&self) -> u32 {
+        calendar::jalali_to_fasli(self.year(), self.month(), self.day()).1
+    }
+
+    /// Return the zoroastrian fasli day
+    fn fasli_day(&self) -> u32 {
+        calendar::jalali_to_fasli(self.year(), self.month(), self.day()).2
+    }
+
+    /// Return avestan name of fasli day
+    fn fasli_day_name(&self) -> &'static str {
+        era::fasli_day_name(self.fasli_month(), self.fasli_day())
+    }
+
+    /// Return the hijri (lunar) year, based on tabular islamic calendar
+    fn hijri_year(&self) -> i32 {
+        calendar::jalali_to_hijri(self.year(), self.month(), self.day()).0
+    }
+    /// Return the hijri (lunar) month
+    fn hijri_month(&self) -> u32 {
+        calendar::jalali_to_hijri(self.year(), self.month(), self.day()).1
+    }
+    /// Return the hijri (lunar) day
+    fn hijri_day(&self) -> u32 {
+        calendar::jalali_to_hijri(self.year(), self.month(), self.day()).2
+    }
+
+    /// Return name of hijri (lunar) month
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use persian_date::structure::{PDate,Reader};
+    ///
+    /// let pdate = PDate::now();
+    /// println!("{}",pdate.hijri_month_name())
+    /// // print like : محرم - صفر - ربیع‌الاول - etc
+    /// ```
+    fn hijri_month_name(&self) -> String {
+        crate::locale::HIJRI_MONTH_NAMES[(self.hijri_month() - 1) as usize].to_string()
+    }
+
     /// Return jalali year is leap or not
     fn is_leap(&self) -> bool;
     /// Return gregorian year is leap or not
@@ -157,37 +271,87 @@ pub trait Reader {
     fn is_mid_night(&self) -> bool;
 }
 
+/// Error of [Setter] methods, the date is left unchanged
+#[cfg(feature = "tz")]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SetError {
+    /// Year is out of supported range
+    InvalidYear,
+    /// Month is not between 1 and 12
+    InvalidMonth,
+    /// Day does not exist in the month
+    InvalidDay,
+    /// Hour, minute or second is out of its range
+    InvalidTime,
+    /// Result is out of range of timestamps
+    OutOfRange,
+}
+
+#[cfg(feature = "tz")]
+impl core::fmt::Display for SetError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            SetError::InvalidYear => "year is out of supported range",
+            SetError::InvalidMonth => "month must be between 1 and 12",
+            SetError::InvalidDay => "day does not exist in the month",
+            SetError::InvalidTime => "hour, minute or second is out of range",
+            SetError::OutOfRange => "date is out of range",
+        })
+    }
+}
+
+#[cfg(feature = "tz")]
+impl std::error::Error for SetError {}
+
 /// Setter Trait for Set/Update/Change data from date
 /// All Add* will be handled to the next date when they exceed the limitation
+///
+/// Set* methods return an error and keep the date unchanged when the result does not exist
+///
+/// # Example
+///
+/// ```
+/// use persian_date::structure::{PDate, Reader, SetError, Setter};
+///
+/// let mut pdate = PDate::from_jalali_date(1403, 12, 30);
+/// assert_eq!(pdate.set_year(1404), Err(SetError::InvalidDay));
+/// assert_eq!(pdate.set_year(persian_date::calendar::MAX_YEAR + 1), Err(SetError::InvalidYear));
+/// assert_eq!(pdate.set_hour(24), Err(SetError::InvalidTime));
+/// assert_eq!(pdate.year(), 1403);
+///
+/// pdate.set_ymd(1404, 12, 29).unwrap();
+/// assert_eq!((pdate.year(), pdate.month(), pdate.day()), (1404, 12, 29));
+/// ```
+#[cfg(feature = "tz")]
 pub trait Setter {
     /// Set jalali year - month - day (update date to this)
-    fn set_ymd(&mut self, year : i32,month : u32,day : u32);
+    fn set_ymd(&mut self, year : i32,month : u32,day : u32) -> Result<(), SetError>;
     /// Set gregorian year - month - day (update date to this)
-    fn set_grg_ymd(&mut self, year : i32,month : u32,day : u32);
-    /// Set jalali year
-    fn set_year(&mut self, year : i32);
-    /// Set gregorian year
-    fn set_grg_year(&mut self, year : i32);
+    fn set_grg_ymd(&mut self, year : i32,month : u32,day : u32) -> Result<(), SetError>;
+    /// Set jalali year, in [MIN_YEAR](crate::calendar::MIN_YEAR)..=[MAX_YEAR](crate::calendar::MAX_YEAR)
+    fn set_year(&mut self, year : i32) -> Result<(), SetError>;
+    /// Set gregorian year, in [MIN_GRG_YEAR](crate::calendar::MIN_GRG_YEAR)..=[MAX_GRG_YEAR](crate::calendar::MAX_GRG_YEAR)
+    fn set_grg_year(&mut self, year : i32) -> Result<(), SetError>;
 
     /// Set jalali month
-    fn set_month(&mut self, month : u32);
+    fn set_month(&mut self, month : u32) -> Result<(), SetError>;
     /// Set gregorian month
-    fn set_grg_month(&mut self, month : u32);
+    fn set_grg_month(&mut self, month : u32) -> Result<(), SetError>;
 
     /// Set jalali day
-    fn set_day(&mut self, day : u32);
+    fn set_day(&mut self, day : u32) -> Result<(), SetError>;
     /// Set gregorian day
-    fn set_grg_day(&mut self, day : u32);
+    fn set_grg_day(&mut self, day : u32) -> Result<(), SetError>;
 
     /// Set hour in day
-    fn set_hour(&mut self, hour : u32);
+    fn set_hour(&mut self, hour : u32) -> Result<(), SetError>;
     /// Set minutes in hour
-    fn set_minute(&mut self, minute : u32);
+    fn set_minute(&mut self, minute : u32) -> Result<(), SetError>;
     /// Set seconds in minute
-    fn set_second(&mut self, second : u32);
+    fn set_second(&mut self, second : u32) -> Result<(), SetError>;
 
     /// Set timestamp milliseconds
-    fn set_time_millis(&mut self,millis : i64);
+    fn set_time_millis(&mut self,millis : i64) -> Result<(), SetError>;
 
     /// Set/Change timezone
     fn set_time_zone(&mut self,timezone : Tz);
@@ -0,0 +1,159 @@
+//! Persian numbers and dates in words
+//!
+//! Used for legal documents and cheques, like `پانزدهم فروردین ماه یک هزار و چهارصد و سه`
+//!
+//! # Example
+//!
+//! ```
+//! use persian_date::words;
+//!
+//! assert_eq!(words::cardinal(1403), "یک هزار و چهارصد و سه");
+//! assert_eq!(words::ordinal(15), "پانزدهم");
+//! assert_eq!(words::ordinal(23), "بیست و سوم");
+//! assert_eq!(words::ordinal(30), "سی‌ام");
+//! ```
+
+use core::fmt::Write;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+const ONES : [&str; 20] = ["صفر", "یک", "دو", "سه", "چهار", "پنج", "شش", "هفت", "هشت", "نه",
+    "ده", "یازده", "دوازده", "سیزده", "چهارده", "پانزده", "شانزده", "هفده", "هجده", "نوزده"];
+const TENS : [&str; 10] = ["", "", "بیست", "سی", "چهل", "پنجاه", "شصت", "هفتاد", "هشتاد", "نود"];
+const HUNDREDS : [&str; 10] = ["", "صد", "دویست", "سیصد", "چهارصد", "پانصد", "ششصد", "هفتصد", "هشتصد", "نهصد"];
+const SCALES : [(u64, &str); 6] = [(1_000_000_000_000_000_000, "کوینتیلیون"), (1_000_000_000_000_000, "کوادریلیون"),
+    (1_000_000_000_000, "تریلیون"), (1_000_000_000, "میلیارد"), (1_000_000, "میلیون"), (1_000, "هزار")];
+
+/// Call `emit` with `(separator, word)` for each word of number
+fn for_each_word(number : i64, mut emit : impl FnMut(&'static str, &'static str) -> core::fmt::Result) -> core::fmt::Result {
+    if number == 0 {
+        return emit("", ONES[0]);
+    }
+    let mut separator = "";
+    if number < 0 {
+        emit("", "منفی")?;
+        separator = " ";
+    }
+    let mut rest = number.unsigned_abs();
+    for (scale, name) in SCALES.into_iter().chain([(1, "")]) {
+        if rest < scale {
+            continue;
+        }
+        let count = (rest / scale) as usize;
+        rest %= scale;
+        let parts = [HUNDREDS[count / 100], if count % 100 < 20 { "" } else { TENS[count % 100 / 10] },
+            match count % 100 { 0 => "", n @ 1..=19 => ONES[n], n => if n % 10 == 0 { "" } else { ONES[n % 10] } }];
+        for part in parts.into_iter().filter(|part| !part.is_empty()) {
+            emit(separator, part)?;
+            separator = " و ";
+        }
+        if !name.is_empty() {
+            emit(" ", name)?;
+        }
+    }
+    Ok(())
+}
+
+/// Write number in persian words
+pub fn write_cardinal(f : &mut impl Write, number : i64) -> core::fmt::Result {
+    for_each_word(number, |separator, word| {
+        f.write_str(separator)?;
+        f.write_str(word)
+    })
+}
+
+/// Write ordinal number in persian words, like `پانزدهم`
+pub fn write_ordinal(f : &mut impl Write, number : i64) -> core::fmt::Result {
+    let mut last = ("", "");
+    for_each_word(number, |separator, word| {
+        f.write_str(last.0)?;
+        f.write_str(last.1)?;
+        last = (separator, word);
+        Ok(())
+    })?;
+    f.write_str(last.0)?;
+    match last.1 {
+        "سه" => f.write_str("سوم"),
+        word if word.ends_with('ی') => write!(f,"{}\u{200c}ام",word),
+        word => write!(f,"{}م",word),
+    }
+}
+
+/// Return number in persian words
+#[cfg(feature = "alloc")]
+pub fn cardinal(number : i64) -> String {
+    let mut out = String::new();
+    let _ = write_cardinal(&mut out, number);
+    out
+}
+
+/// Return ordinal number in persian words, like `پانزدهم`
+#[cfg(feature = "alloc")]
+pub fn ordinal(number : i64) -> String {
+    let mut out = String::new();
+    let _ = write_ordinal(&mut out, number);
+    out
+}
+
+/// Read a number in persian words from start of input, like `یک هزار و چهارصد و سه` or `پانزدهم`
+///
+/// Return the number and the rest of input
+#[cfg(feature = "tz")]
+pub(crate) fn take_number(s : &str) -> Option<(i64, &str)> {
+    let mut total : u64 = 0;
+    let mut current : u64 = 0;
+    let mut rest = s;
+    let mut matched = false;
+    while let Some((value, is_scale, len)) = match_word(rest) {
+        let mut after = &rest[len..];
+        let mut ordinal = false;
+        for suffix in ["\u{200c}ام", "ام", "م"] {
+            if let Some(stripped) = after.strip_prefix(suffix).filter(|s| !starts_with_letter(s)) {
+                after = stripped;
+                ordinal = true;
+                break;
+            }
+        }
+        if starts_with_letter(after) {
+            break;
+        }
+        if is_scale {
+            total = total.checked_add(current.max(1).checked_mul(value)?)?;
+            current = 0;
+        } else {
+            current = current.checked_add(value)?;
+        }
+        matched = true;
+        rest = after;
+        if ordinal || value == 0 {
+            break;
+        }
+        match [" و ", " "].iter().find_map(|separator| rest.strip_prefix(separator).filter(|next| match_word(next).is_some())) {
+            Some(next) => rest = next,
+            None => break,
+        }
+    }
+    if !matched {
+        return None;
+    }
+    Some((i64::try_from(total.checked_add(current)?).ok()?, rest))
+}
+
+/// Match longest number word at start of input, return `(value, is scale, length)`
+#[cfg(feature = "tz")]
+fn match_word(s : &str) -> Option<(u64, bool, usize)> {
+    let ones = ONES.iter().enumerate().map(|(value, word)| (value as u64, false, *word));
+    let tens = TENS.iter().enumerate().skip(2).map(|(value, word)| (value as u64 * 10, false, *word));
+    let hundreds = HUNDREDS.iter().enumerate().skip(1).map(|(value, word)| (value as u64 * 100, false, *word));
+    let scales = SCALES.iter().map(|(value, word)| (*value, true, *word));
+    let aliases = [(18, false, "هیجده"), (3, false, "سو"), (1, false, "اول"), (1, false, "نخست")];
+    ones.chain(tens).chain(hundreds).chain(scales).chain(aliases)
+        .filter(|(_, _, word)| s.starts_with(word))
+        .max_by_key(|(_, _, word)| word.len())
+        .map(|(value, is_scale, word)| (value, is_scale, word.len()))
+}
+
+#[cfg(feature = "tz")]
+fn starts_with_letter(s : &str) -> bool {
+    s.chars().next().is_some_and(char::is_alphabetic)
+}
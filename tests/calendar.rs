@@ -0,0 +1,16 @@
+#![cfg(feature = "tz")]
+
+use persian_date::{calendar, grid};
+use persian_date::structure::NaiveJalaliDate;
+
+#[test]
+fn julian_day_out_of_range() {
+    assert_eq!(NaiveJalaliDate::from_jdn_opt(calendar::MIN_JDN).unwrap().to_string(), format!("{}-01-01", calendar::MIN_YEAR));
+    assert!(NaiveJalaliDate::from_jdn_opt(calendar::MIN_JDN - 1).is_none());
+    for jdn in [1_000_000_000, 1_000_000_000_000, i64::MAX, i64::MIN] {
+        assert!(NaiveJalaliDate::from_jdn_opt(jdn).is_none());
+    }
+    assert!(std::panic::catch_unwind(|| NaiveJalaliDate::from_jdn(1_000_000_000)).is_err());
+    assert!(grid::month_grid(calendar::MAX_YEAR, 12, 0).is_none());
+    assert!(grid::month_grid(calendar::MAX_YEAR, 11, 0).is_some());
+}
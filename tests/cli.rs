@@ -0,0 +1,161 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+/// Run pdate with arguments and stdin, in Tehran unless `-z` is given
+fn pdate(args : &[&str], input : &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_pdate")).args(args).env("TZ", "Asia/Tehran")
+        .stdin(Stdio::piped()).stdout(Stdio::piped()).stderr(Stdio::piped()).spawn().unwrap();
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(args : &[&str]) -> String {
+    let output = pdate(args, "");
+    assert!(output.status.success(), "{:?}: {}", args, String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout).unwrap()
+}
+
+fn stderr(args : &[&str]) -> String {
+    let output = pdate(args, "");
+    assert_eq!(output.status.code(), Some(1), "{:?}", args);
+    String::from_utf8(output.stderr).unwrap()
+}
+
+#[test]
+fn date_forms() {
+    let format = "+%Y/%m/%d %H:%M:%S";
+    assert_eq!(stdout(&["-d", "@1712136600", format]), "1403/01/15 13:00:00\n");
+    assert_eq!(stdout(&["-d", "@۱۷۱۲۱۳۶۶۰۰", "-z", "UTC", format]), "1403/01/15 09:30:00\n");
+    assert_eq!(stdout(&["-d", "2024-04-03T09:30:00Z", format]), "1403/01/15 13:00:00\n");
+    // years from 1700 are gregorian, earlier years are jalali
+    assert_eq!(stdout(&["-d", "2024-04-03 13:00", format]), "1403/01/15 13:00:00\n");
+    assert_eq!(stdout(&["-d", "1700/01/01", format]), "1078/10/12 00:00:00\n");
+    assert_eq!(stdout(&["-d", "1403/01/15 13:00", format]), "1403/01/15 13:00:00\n");
+    assert_eq!(stdout(&["-d", "1699-12-29", format]), "1699/12/29 00:00:00\n");
+    assert_eq!(stdout(&["-d", "1403/01/15", "-g", "+%Y-%m-%d"]), "2024-04-03\n");
+    assert!(stderr(&["-d", "1402/12/30"]).contains("invalid date '1402/12/30'"));
+}
+
+#[test]
+fn natural_phrases() {
+    let today = stdout(&["+%Y/%m/%d"]);
+    assert_eq!(stdout(&["-d", "امروز", "+%Y/%m/%d"]), today);
+    assert_eq!(stdout(&["-d", "فردا ساعت ۱۰", "+%H:%M"]), "10:00\n");
+    assert_eq!(stdout(&["-d", "پانزدهم مهر ماه ۱۴۰۲", "+%Y/%m/%d"]), "1402/07/15\n");
+    assert!(stderr(&["-d", "پس فردای دیروز"]).contains("invalid date"));
+}
+
+#[test]
+fn add_clamps_to_month_length() {
+    assert_eq!(stdout(&["-d", "1403/12/30", "--add", "1y", "+%Y/%m/%d"]), "1404/12/29\n");
+    assert_eq!(stdout(&["-d", "1403/06/31", "--add", "1mo", "+%Y/%m/%d"]), "1403/07/30\n");
+    assert_eq!(stdout(&["-d", "1403/01/15 13:00", "--add", "-1mo1d", "+%Y/%m/%d %H:%M"]), "1402/12/14 13:00\n");
+    assert_eq!(stdout(&["-d", "1403/01/15", "--add", "1w2h30m", "+%Y/%m/%d %H:%M"]), "1403/01/22 02:30\n");
+    assert!(stderr(&["--add", "3x"]).contains("invalid duration '3x'"));
+    assert_eq!(stderr(&["--add", "d"]), "pdate: invalid duration 'd'\n");
+    assert_eq!(stderr(&["--add", "-m"]), "pdate: invalid duration '-m'\n");
+    assert_eq!(stderr(&["--add", "1d2"]), "pdate: invalid duration '1d2'\n");
+}
+
+#[test]
+fn out_of_range() {
+    for args in [
+        &["-d", "@99999999999999"][..],
+        &["-d", "@-9223372036854775808"],
+        &["--add", "100000000000d"],
+        &["--add", "9223372036854775807s"],
+        &["--add", "99999999999999999999d"],
+        &["--add", "300000y"],
+        &["--add", "9223372036854775807y"],
+        &["-d", "100000000 روز بعد"],
+    ] {
+        assert_eq!(stderr(args), "pdate: date is out of range\n", "{:?}", args);
+    }
+}
+
+#[test]
+fn cal_arguments() {
+    // a single number above 12 is a year, otherwise a month of current year
+    let year = stdout(&["cal", "--digits", "latin", "1403"]);
+    assert!(year.starts_with("                              1403\n"));
+    assert_eq!(stdout(&["cal", "۱۴۰۳", "--digits", "latin"]), year);
+    let month = stdout(&["cal", "--digits", "latin", "5", "1403"]);
+    assert!(month.starts_with("     مرداد 1403\n ش  ی  د  س  چ  پ  ج\n       1  2  3  4  5\n"));
+    let current = stdout(&["+%m %Y"]);
+    let (current_month, current_year) = current.trim().split_once(' ').unwrap();
+    assert_eq!(stdout(&["cal", "5"]), stdout(&["cal", "5", current_year]));
+    assert_eq!(stdout(&["cal"]), stdout(&["cal", current_month, current_year]));
+    assert_eq!(stderr(&["cal", "13", "1403"]), "pdate: invalid month 13 of year 1403\n");
+    assert!(stderr(&["cal", "0"]).starts_with("pdate: invalid month 0 of year"));
+    assert!(stderr(&["cal", "1", "2", "3"]).starts_with("pdate: invalid argument '3'\n"));
+}
+
+fn filter(args : &[&str], input : &str) -> String {
+    let output = pdate(&[&["filter"], args].concat(), input);
+    assert!(output.status.success(), "{:?}: {}", args, String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn filter_known_formats() {
+    let input = "start 2024-04-03T09:30:00Z ok\nno timestamp here\n[03/Apr/2024:09:30:00 +0000] GET /\n";
+    assert_eq!(filter(&[], input), "start 1403/01/15 13:00:00 ok\nno timestamp here\n[03/Apr/2024:09:30:00 +0000] GET /\n");
+    assert_eq!(filter(&["-f", "rfc3339,clf", "-t", "%Y/%m/%d"], input), "start 1403/01/15 ok\nno timestamp here\n[1403/01/15] GET /\n");
+    assert_eq!(filter(&["-f", "epoch,epoch-ms", "-z", "UTC"], "1712136600 1712136600250 17121366\n"), "1403/01/15 09:30:00 1403/01/15 09:30:00 17121366\n");
+}
+
+#[test]
+fn filter_regex() {
+    // only the `ts` group, the first group or the whole match is rewritten
+    assert_eq!(filter(&["-r", r"ts=(\d+)", "-f", "epoch"], "ts=1712136600 id=1712136600\n"), "ts=1403/01/15 13:00:00 id=1712136600\n");
+    assert_eq!(filter(&["-r", r"at (?P<ts>\S+ \S+) by", "-i", "%d.%m.%Y %H:%M", "-t", "%Y/%m/%d %H:%M"], "at 03.04.2024 13:00 by me\n"),
+        "at 1403/01/15 13:00 by me\n");
+    assert_eq!(filter(&["-r", r"\d{2}\.\d{2}\.\d{4}", "-i", "%d.%m.%Y"], "03.04.2024\n"), "03.04.2024\n");
+}
+
+#[test]
+fn filter_passes_through() {
+    let input = "not a date\n\u{fffe} 2024-04-03T09:30:00Z\n";
+    assert_eq!(filter(&["-f", "iso"], input), "not a date\n\u{fffe} 2024-04-03T09:30:00Z\n");
+    // out of range and not finite timestamps are left unchanged
+    assert_eq!(filter(&["-r", r"\d+", "-f", "epoch-ms"], "99999999999999999 9223372036854775807\n"), "99999999999999999 9223372036854775807\n");
+    assert_eq!(filter(&["-r", r"\w+", "-f", "epoch"], "nan inf 1e400\n"), "nan inf 1e400\n");
+    let output = pdate(&["filter"], "");
+    assert!(output.status.success() && output.stdout.is_empty());
+}
+
+fn csv(args : &[&str], input : &str) -> (Option<i32>, String, String) {
+    let output = pdate(&[&["csv"], args].concat(), input);
+    (output.status.code(), String::from_utf8(output.stdout).unwrap(), String::from_utf8(output.stderr).unwrap())
+}
+
+#[test]
+fn csv_columns() {
+    let input = "id,start,end,note\n1,1403/01/15,1403/12/30,x\n2, 1402/07/01 ,,y\n";
+    let expected = "id,start,end,note\n1,2024-04-03,2025-03-20,x\n2,2023-09-23,,y\n";
+    assert_eq!(csv(&["-c", "start,end"], input), (Some(0), expected.to_string(), String::new()));
+    assert_eq!(csv(&["-c", "2,3"], input).1, expected);
+    assert_eq!(csv(&["--to-jalali", "-c", "start,end"], expected).1, "id,start,end,note\n1,1403/01/15,1403/12/30,x\n2,1402/07/01,,y\n");
+    assert_eq!(csv(&["--no-header", "-c", "1", "-d", ";", "-i", "%Y%m%d", "-o", "%d.%m.%Y"], "14030115;a\n").1, "03.04.2024;a\n");
+    assert_eq!(csv(&["--to-jalali", "-c", "1", "--no-header", "-o", "%d %B %Y"], "2024-04-03\n").1, "15 فروردین 1403\n");
+    assert_eq!(csv(&["-c", "missing"], input), (Some(1), String::new(), "pdate: unknown column 'missing'\n".to_string()));
+}
+
+#[test]
+fn csv_invalid_rows() {
+    let input = "id,date\n1,1402/12/30\n2,1403/01/15\n3,tomorrow\n";
+    let (code, output, errors) = csv(&["-c", "date"], input);
+    assert_eq!(code, Some(1));
+    assert_eq!(output, "id,date\n1,1402/12/30\n2,2024-04-03\n3,tomorrow\n");
+    assert_eq!(errors, "pdate: line 2, column 2: invalid date '1402/12/30'\npdate: line 4, column 2: invalid date 'tomorrow'\npdate: 2 invalid dates\n");
+
+    let (code, output, errors) = csv(&["-c", "date", "--strict"], input);
+    assert_eq!(code, Some(1));
+    assert_eq!(output, "id,date\n");
+    assert_eq!(errors, "pdate: line 2, column 2: invalid date '1402/12/30'\npdate: stopped on invalid date\n");
+
+    // gregorian dates out of the jalali range are invalid too
+    let (code, output, errors) = csv(&["--to-jalali", "-c", "1", "--no-header"], "-262000-01-01\n");
+    assert_eq!((code, output.as_str()), (Some(1), "-262000-01-01\n"));
+    assert!(errors.starts_with("pdate: line 1, column 1: invalid date '-262000-01-01'\n"));
+}
@@ -0,0 +1,18 @@
+#![cfg(feature = "time")]
+
+use persian_date::calendar;
+use persian_date::interop::OutOfRange;
+use persian_date::structure::NaiveJalaliDate;
+use time::{Date, Month};
+
+#[test]
+fn time_date_out_of_range() {
+    let date = Date::from_calendar_date(2024, Month::April, 3).unwrap();
+    assert_eq!(NaiveJalaliDate::try_from(date).unwrap().to_string(), "1403-01-15");
+    let date = Date::from_calendar_date(calendar::MAX_GRG_YEAR, Month::December, 31).unwrap();
+    assert_eq!(NaiveJalaliDate::try_from(date).unwrap().year(), calendar::MAX_YEAR);
+    for year in [calendar::MAX_GRG_YEAR + 1, 999_999, calendar::MIN_GRG_YEAR - 1, -999_999] {
+        let date = Date::from_calendar_date(year, Month::January, 1).unwrap();
+        assert_eq!(NaiveJalaliDate::try_from(date), Err(OutOfRange), "{}", year);
+    }
+}
@@ -0,0 +1,27 @@
+use persian_date::locale::Locale;
+
+#[test]
+fn names_in_range() {
+    assert_eq!(Locale::Persian.month_name(1), "فروردین");
+    assert_eq!(Locale::English.month_name(12), "Esfand");
+    assert_eq!(Locale::English.day_name(6), "Friday");
+    assert_eq!(Locale::English.grg_month_name(12), "December");
+}
+
+#[test]
+#[should_panic(expected = "month must be between 1 and 12")]
+fn month_zero() {
+    Locale::Persian.month_name(0);
+}
+
+#[test]
+#[should_panic(expected = "month must be between 1 and 12")]
+fn month_after_end() {
+    Locale::English.grg_month_name(13);
+}
+
+#[test]
+#[should_panic(expected = "day of week must be between 0 and 6")]
+fn day_after_end() {
+    Locale::Persian.day_name(7);
+}
@@ -0,0 +1,37 @@
+#![cfg(feature = "tz")]
+
+use persian_date::locale::Locale;
+use persian_date::structure::{NaiveJalaliDate, PDate};
+
+#[test]
+fn day_name_forms() {
+    let pdate = PDate::from_time_millis(1712136600000);
+    assert_eq!(pdate.format_moment("dd|ddd|dddd").to_string(), "چ|چ|چهارشنبه");
+    assert_eq!(pdate.format_moment_localized("dd|ddd|dddd", Locale::English).to_string(), "We|Wed|Wednesday");
+}
+
+#[test]
+fn month_name_forms() {
+    let pdate = PDate::from_time_millis(1712136600000);
+    assert_eq!(pdate.format_moment("jMMM|jMMMM").to_string(), "فروردین|فروردین");
+    assert_eq!(pdate.format_moment_localized("jMMM|jMMMM", Locale::English).to_string(), "Far|Farvardin");
+    assert_eq!(pdate.format_moment("MMM|MMMM").to_string(), "Apr|April");
+}
+
+#[test]
+fn ordinal_days() {
+    let pdate = PDate::from_time_millis(1712136600000);
+    assert_eq!(pdate.format_moment("jDo").to_string(), "پانزدهم");
+    assert_eq!(pdate.format_moment("Do").to_string(), "سوم");
+}
+
+#[test]
+fn round_trip() {
+    let pdate = PDate::from_time_millis(1712136600000);
+    for pattern in ["jYYYY/jMM/jDD HH:mm", "dddd jDo jMMMM jYYYY HH:mm", "dd jD jMMM jYYYY H:m", "Do MMMM YYYY HH:mm:ss"] {
+        let text = pdate.format_moment(pattern).to_string();
+        assert_eq!(PDate::parse_moment(&text, pattern), Ok(PDate::from_time_millis(1712136600000)), "{}", pattern);
+    }
+    let date = NaiveJalaliDate::parse_moment("پانزدهم فروردین 1403", "jDo jMMM jYYYY").unwrap();
+    assert_eq!(date.to_string(), "1403-01-15");
+}
@@ -0,0 +1,57 @@
+#![cfg(feature = "tz")]
+
+use persian_date::calendar;
+use persian_date::parse::ParseError;
+use persian_date::structure::{PDate, Reader};
+
+#[test]
+fn distance_out_of_range() {
+    let reference = PDate::from_time_millis(1712124000000); // 1403/01/15 09:30 in Tehran
+    for phrase in [
+        "100000000 روز بعد",
+        "100000000 روز پیش",
+        "300000 سال بعد",
+        "300000 سال پیش",
+        "9000000000000000000 روز بعد",
+        "9000000000000000000 هفته بعد",
+        "9000000000000000000 ماه پیش",
+        "9000000000000000000 سال بعد",
+        "9000000000000000000 ساعت بعد",
+        "2000000000000 دقیقه بعد",
+    ] {
+        assert_eq!(PDate::parse_natural(phrase, &reference).err(), Some(ParseError::OutOfRange), "{}", phrase);
+    }
+}
+
+#[test]
+fn distance_in_range() {
+    let reference = PDate::from_time_millis(1712124000000); // 1403/01/15 09:30 in Tehran
+    let date = PDate::parse_natural("1000 سال بعد", &reference).unwrap();
+    assert_eq!(date.start().jalali_date().to_string(), "2403-01-15");
+    let date = PDate::parse_natural("۱۰۰۰۰ روز پیش", &reference).unwrap();
+    assert_eq!(date.start().jalali_date().to_string(), "1375-08-26");
+}
+
+#[test]
+fn periods_at_bounds() {
+    let reference = PDate::from_jalali_date(calendar::MAX_YEAR, 12, 29);
+    assert_eq!(PDate::parse_natural("سال دیگه", &reference).err(), Some(ParseError::OutOfRange));
+    assert_eq!(PDate::parse_natural("ماه آینده", &reference).err(), Some(ParseError::OutOfRange));
+    assert_eq!(PDate::parse_natural("فردا", &reference).err(), Some(ParseError::OutOfRange));
+    assert_eq!(PDate::parse_natural("امروز ساعت ۱۰", &reference).unwrap().start().hour(), 10);
+
+    let reference = PDate::from_jalali_date(calendar::MIN_YEAR, 1, 1);
+    assert_eq!(PDate::parse_natural("پارسال", &reference).err(), Some(ParseError::OutOfRange));
+    assert_eq!(PDate::parse_natural("دیروز", &reference).err(), Some(ParseError::OutOfRange));
+}
+
+#[test]
+fn night_hours() {
+    let reference = PDate::from_time_millis(1712124000000); // 1403/01/15 09:30 in Tehran
+    let hour = |phrase| PDate::parse_natural(phrase, &reference).unwrap().start().hour();
+    assert_eq!(hour("ساعت ۱۲ شب"), 0);
+    assert_eq!(hour("ساعت ۱ شب"), 1);
+    assert_eq!(hour("ساعت ۱۱ شب"), 23);
+    assert_eq!(hour("ساعت ۸ شب"), 20);
+    assert_eq!(hour("ساعت ۱۲ ظهر"), 12);
+}
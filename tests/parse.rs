@@ -0,0 +1,46 @@
+#![cfg(feature = "tz")]
+
+use persian_date::calendar;
+use persian_date::parse::ParseError;
+use persian_date::structure::{NaiveJalaliDate, PDate, Reader};
+
+#[test]
+fn timestamp_out_of_range() {
+    assert_eq!(PDate::parse_from_str("99999999999999", "%s"), Err(ParseError::OutOfRange));
+    assert_eq!(PDate::parse_from_str("99999999999999999999", "%s"), Err(ParseError::OutOfRange));
+    assert_eq!(PDate::parse_moment("99999999999999999", "x"), Err(ParseError::OutOfRange));
+    assert_eq!(PDate::parse_moment("-99999999999999999", "x"), Err(ParseError::OutOfRange));
+    assert_eq!(PDate::parse_moment("1712136600000", "x").unwrap().time_millis(), 1712136600000);
+    assert_eq!(PDate::parse_from_str("1712136600", "%s").unwrap().time_millis(), 1712136600000);
+}
+
+#[test]
+fn hijri_year_out_of_range() {
+    assert_eq!(NaiveJalaliDate::parse_from_str("999999/01/01", "%hY/%hm/%hd"), Err(ParseError::OutOfRange));
+    assert_eq!(PDate::parse_from_str("999999/01/01", "%hY/%hm/%hd"), Err(ParseError::OutOfRange));
+    assert_eq!(PDate::parse_from_str("-999999/01/01", "%hY/%hm/%hd"), Err(ParseError::OutOfRange));
+
+    let max = format!("{}/12/01", calendar::MAX_HIJRI_YEAR);
+    let date = NaiveJalaliDate::parse_from_str(&max, "%hY/%hm/%hd").unwrap();
+    assert!(date.year() <= calendar::MAX_YEAR);
+    let min = format!("{}/01/01", calendar::MIN_HIJRI_YEAR);
+    let date = NaiveJalaliDate::parse_from_str(&min, "%hY/%hm/%hd").unwrap();
+    assert!(date.year() >= calendar::MIN_YEAR);
+}
+
+#[test]
+fn hijri_day_in_month_length() {
+    // ramadan 1445 has 30 days and shawwal 29 days in tabular calendar
+    assert_eq!(calendar::hijri_month_days(1445, 9), 30);
+    assert_eq!(calendar::hijri_month_days(1445, 10), 29);
+    let date = NaiveJalaliDate::parse_from_str("1445/09/30", "%hY/%hm/%hd").unwrap();
+    assert_eq!(date.to_string(), "1403-01-21");
+    assert_eq!(NaiveJalaliDate::parse_from_str("1445/10/30", "%hY/%hm/%hd"), Err(ParseError::OutOfRange));
+    assert_eq!(NaiveJalaliDate::parse_from_str("1445/13/01", "%hY/%hm/%hd"), Err(ParseError::OutOfRange));
+    assert_eq!(NaiveJalaliDate::parse_from_str("1445/09/00", "%hY/%hm/%hd"), Err(ParseError::OutOfRange));
+}
+
+#[test]
+fn era_year_out_of_range() {
+    assert_eq!(NaiveJalaliDate::parse_from_str("-2147483648/01/01", "%EI/%m/%d"), Err(ParseError::OutOfRange));
+}
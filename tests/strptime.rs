@@ -0,0 +1,83 @@
+#![cfg(feature = "tz")]
+
+use chrono_tz::Tz;
+use persian_date::format::Format;
+use persian_date::parse::ParseError;
+use persian_date::structure::{NaiveJalaliDate, PDate, Reader};
+
+#[test]
+fn round_trip() {
+    let pdate = PDate::from_time_millis(1712136600000);
+    for pattern in [
+        "%Y/%m/%d %H:%M:%S",
+        "%A %d %B %Y %I:%M %P",
+        "%Y-%m-%dT%H:%M:%S%.f%:z",
+        "%Y%m%d%H%M%S%z",
+        "%gY-%gm-%gd %H:%M",
+        "%hY/%hm/%hd %H",
+        "%Od %OB %OY، %H:%M",
+        "%s",
+    ] {
+        let text = pdate.format(pattern);
+        assert_eq!(PDate::parse_from_str(&text, pattern), Ok(PDate::from_time_millis(1712136600000)), "{} : {}", pattern, text);
+    }
+}
+
+#[test]
+fn digits_and_padding() {
+    let date = NaiveJalaliDate::parse_from_str("۱۴۰۳/۱/۵", "%Y/%m/%d").unwrap();
+    assert_eq!(date.to_string(), "1403-01-05");
+    let date = NaiveJalaliDate::parse_from_str("14030115", "%Y%m%d").unwrap();
+    assert_eq!(date.to_string(), "1403-01-15");
+    let date = NaiveJalaliDate::parse_from_str("1403/ 1/ 5", "%Y/%_m/%e").unwrap();
+    assert_eq!(date.to_string(), "1403-01-05");
+}
+
+#[test]
+fn offsets_and_zones() {
+    let pdate = PDate::parse_from_str("1403/01/15 09:30 +00:00", "%Y/%m/%d %H:%M %:z").unwrap();
+    assert_eq!(pdate.time_millis(), 1712136600000);
+    let pdate = PDate::parse_from_str_tz("1403/01/15 09:30", "%Y/%m/%d %H:%M", Tz::UTC).unwrap();
+    assert_eq!(pdate.time_millis(), 1712136600000);
+    assert_eq!(pdate.time_zone(), Tz::UTC);
+}
+
+#[test]
+fn second_fraction() {
+    let pdate = PDate::parse_from_str("1403/01/15 13:00:00.250", "%Y/%m/%d %H:%M:%S%.f").unwrap();
+    assert_eq!(pdate.time_millis(), 1712136600250);
+    let pdate = PDate::parse_from_str("1403/01/15 13:00:00", "%Y/%m/%d %H:%M:%S%.f").unwrap();
+    assert_eq!(pdate.time_millis(), 1712136600000);
+}
+
+#[test]
+fn errors() {
+    assert_eq!(NaiveJalaliDate::parse_from_str("1403/01", "%Y/%m/%d"), Err(ParseError::TooShort));
+    assert_eq!(NaiveJalaliDate::parse_from_str("1403/01/15 x", "%Y/%m/%d"), Err(ParseError::TooLong));
+    assert_eq!(NaiveJalaliDate::parse_from_str("1403-01-15", "%Y/%m/%d"), Err(ParseError::Invalid));
+    assert_eq!(NaiveJalaliDate::parse_from_str("1402/12/30", "%Y/%m/%d"), Err(ParseError::OutOfRange));
+    assert_eq!(NaiveJalaliDate::parse_from_str("1403/01", "%Y/%m"), Err(ParseError::NotEnough));
+    assert_eq!(PDate::parse_from_str("1403/01/15 24:00", "%Y/%m/%d %H:%M"), Err(ParseError::OutOfRange));
+    assert_eq!(PDate::parse_from_str("1403/01/15 13 ب.ظ", "%Y/%m/%d %I %p"), Err(ParseError::OutOfRange));
+}
+
+#[test]
+fn fraction_without_padding() {
+    assert_eq!(PDate::parse_from_str("1403/01/15 13:00:00. 5", "%Y/%m/%d %H:%M:%S.%f"), Err(ParseError::Invalid));
+    assert_eq!(PDate::parse_from_str("1403/01/15 13:00:00. 5", "%Y/%m/%d %H:%M:%S%.f"), Err(ParseError::Invalid));
+    let pdate = PDate::parse_from_str("1403/01/15 13:00:00.000000005", "%Y/%m/%d %H:%M:%S.%f").unwrap();
+    assert_eq!(pdate.time_millis(), 1712136600000);
+}
+
+#[test]
+fn midnight_and_noon_in_12_hours() {
+    let pattern = "%Y/%m/%d %I:%M %p";
+    let midnight = PDate::from_jalali_date(1403, 1, 15);
+    assert_eq!(midnight.format(pattern), "1403/01/15 12:00 ق.ظ");
+    assert_eq!(PDate::parse_from_str(&midnight.format(pattern), pattern), Ok(PDate::from_jalali_date(1403, 1, 15)));
+    // 1403/01/15 12:00 in Tehran
+    let noon = PDate::from_time_millis(1712133000000);
+    assert_eq!(noon.format(pattern), "1403/01/15 12:00 ب.ظ");
+    assert_eq!(PDate::parse_from_str(&noon.format(pattern), pattern), Ok(PDate::from_time_millis(1712133000000)));
+    assert_eq!(PDate::parse_from_str("1403/01/15 00:00 ق.ظ", pattern), Err(ParseError::OutOfRange));
+}